@@ -1,5 +1,7 @@
 use std::fmt::{self, Display, Formatter};
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use semver::Version;
 
@@ -35,12 +37,21 @@ pub enum Command {
     #[command(visible_alias = "w")]
     Watch(CompileCommand),
 
+    /// Initializes a new project from a template
+    Init(InitCommand),
+
     /// Processes an input file to extract provided metadata
     Query(QueryCommand),
 
+    /// Compiles an input file and reports statistics about the document
+    Stats(StatsCommand),
+
     /// Lists all discovered fonts in system and custom font paths
     Fonts(FontsCommand),
 
+    /// Formats source files in place
+    Format(FormatCommand),
+
     /// Self update the Typst CLI
     #[cfg_attr(not(feature = "self-update"), doc = " (disabled)")]
     Update(UpdateCommand),
@@ -53,7 +64,8 @@ pub struct CompileCommand {
     #[clap(flatten)]
     pub common: SharedArgs,
 
-    /// Path to output file (PDF, PNG, or SVG)
+    /// Path to output file (PDF, PNG, or SVG). Use `{n}` in the path to write
+    /// each page to its own file, numbered from one
     pub output: Option<PathBuf>,
 
     /// The format of the output file, inferred from the extension by default
@@ -68,11 +80,102 @@ pub struct CompileCommand {
     #[arg(long = "ppi", default_value_t = 144.0)]
     pub ppi: f32,
 
+    /// Which pages to export, as a comma-separated list of page numbers and
+    /// ranges like `2,5-7,10-`. Exports all pages by default
+    #[arg(long = "pages", value_delimiter = ',')]
+    pub pages: Option<Vec<PageRange>>,
+
+    /// An ICC profile to embed as the output intent of exported PDFs,
+    /// describing the device the document is meant to be printed on
+    #[arg(long = "output-intent", value_name = "ICC_PROFILE")]
+    pub output_intent: Option<PathBuf>,
+
+    /// Reserves an empty signature field in exported PDFs, which external
+    /// tools can then sign without rewriting the file
+    #[arg(long = "signature-placeholder")]
+    pub signature_placeholder: bool,
+
+    /// Treats warnings as errors and also rejects constructs the parser had to
+    /// guess (like unclosed quotes) and values implicitly displayed as text
+    #[arg(long = "strict")]
+    pub strict: bool,
+
+    /// Checks for common mistakes and reports them as warnings. Takes a
+    /// comma-separated list of lints, in addition to those enabled in the
+    /// `[lints]` table of the project's typst.toml
+    #[arg(long = "lint", value_delimiter = ',')]
+    pub lints: Vec<Lint>,
+
+    /// Compiles and exports the document the given number of times without
+    /// any caching and reports how long each stage took, instead of writing
+    /// the output
+    #[arg(long = "bench-internal", value_name = "RUNS", hide = true)]
+    pub bench_internal: Option<NonZeroUsize>,
+
     /// Produces a flamegraph of the compilation process
     #[arg(long = "flamegraph", value_name = "OUTPUT_SVG")]
     pub flamegraph: Option<Option<PathBuf>>,
 }
 
+/// A page number or an inclusive range of page numbers whose start or end
+/// may be omitted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PageRange {
+    /// The first page in the range, or the first page of the document.
+    pub start: Option<NonZeroUsize>,
+    /// The last page in the range, or the last page of the document.
+    pub end: Option<NonZeroUsize>,
+}
+
+impl PageRange {
+    /// Whether the given page number is part of the range.
+    pub fn contains(&self, page: NonZeroUsize) -> bool {
+        self.start.map_or(true, |start| start <= page)
+            && self.end.map_or(true, |end| page <= end)
+    }
+}
+
+impl FromStr for PageRange {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |s: &str| -> Result<Option<NonZeroUsize>, Self::Err> {
+            if s.is_empty() {
+                return Ok(None);
+            }
+            s.trim().parse().map(Some).map_err(|_| "page numbers start at 1")
+        };
+
+        let range = match s.split_once('-') {
+            Some((start, end)) => Self { start: parse(start)?, end: parse(end)? },
+            None => {
+                let page = parse(s)?.ok_or("page number must not be empty")?;
+                Self { start: Some(page), end: Some(page) }
+            }
+        };
+
+        if let (Some(start), Some(end)) = (range.start, range.end) {
+            if start > end {
+                return Err("page range must not end before it starts");
+            }
+        }
+
+        Ok(range)
+    }
+}
+
+/// Initializes a new project from a template
+#[derive(Debug, Clone, Parser)]
+pub struct InitCommand {
+    /// The template to use: `letter`, `report`, or a package like
+    /// `@preview/name:1.0.0` with a `template` directory
+    pub template: String,
+
+    /// The directory to create the project in, named after the template by
+    /// default
+    pub dir: Option<PathBuf>,
+}
+
 /// Processes an input file to extract provided metadata
 #[derive(Debug, Clone, Parser)]
 pub struct QueryCommand {
@@ -96,6 +199,18 @@ pub struct QueryCommand {
     pub format: SerializationFormat,
 }
 
+/// Compiles an input file and reports statistics about the document
+#[derive(Debug, Clone, Parser)]
+pub struct StatsCommand {
+    /// Shared arguments
+    #[clap(flatten)]
+    pub common: SharedArgs,
+
+    /// The format to serialize in
+    #[clap(long = "format", default_value = "json")]
+    pub format: SerializationFormat,
+}
+
 // Output file format for query command
 #[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
 pub enum SerializationFormat {
@@ -131,6 +246,19 @@ pub struct SharedArgs {
     pub diagnostic_format: DiagnosticFormat,
 }
 
+/// Formats source files in place
+#[derive(Debug, Clone, Parser)]
+pub struct FormatCommand {
+    /// Paths to the source files to format. Files with syntax errors are left
+    /// unchanged
+    #[clap(required = true)]
+    pub inputs: Vec<PathBuf>,
+
+    /// Only checks whether the files are formatted, without changing them
+    #[arg(long)]
+    pub check: bool,
+}
+
 /// Lists all discovered fonts in system and custom font paths
 #[derive(Debug, Clone, Parser)]
 pub struct FontsCommand {
@@ -146,6 +274,30 @@ pub struct FontsCommand {
     /// Also lists style variants of each font family
     #[arg(long)]
     pub variants: bool,
+
+    /// Prints detailed information about each font of the given family
+    #[arg(long, value_name = "FAMILY")]
+    pub info: Option<String>,
+}
+
+/// An opt-in check for common mistakes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, ValueEnum)]
+pub enum Lint {
+    /// Multiple consecutive spaces in markup, which collapse into one.
+    DoubleSpaces,
+    /// Quotes that stay straight because smart quotes are disabled.
+    StraightQuotes,
+    /// Headings that skip a level, like a level-three heading directly after
+    /// a level-one heading.
+    HeadingLevels,
+    /// Labels that are never referenced with `@label` syntax.
+    UnusedLabels,
+    /// `TODO` markers in comments and text.
+    Todo,
+    /// Variables and imports that are never read while compiling. Top-level
+    /// definitions of files other than the main file are not checked as other
+    /// files may import them.
+    UnusedBindings,
 }
 
 /// Which format to use for diagnostics.
@@ -196,3 +348,42 @@ impl Display for OutputFormat {
             .fmt(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn range(s: &str) -> (Option<usize>, Option<usize>) {
+        let range: PageRange = s.parse().unwrap();
+        (range.start.map(NonZeroUsize::get), range.end.map(NonZeroUsize::get))
+    }
+
+    #[test]
+    fn test_parse_page_range() {
+        assert_eq!(range("3"), (Some(3), Some(3)));
+        assert_eq!(range("2-5"), (Some(2), Some(5)));
+        assert_eq!(range(" 2 - 5 "), (Some(2), Some(5)));
+        assert_eq!(range("10-"), (Some(10), None));
+        assert_eq!(range("-4"), (None, Some(4)));
+        assert_eq!(range("-"), (None, None));
+        assert_eq!("".parse::<PageRange>(), Err("page number must not be empty"));
+        assert_eq!("0".parse::<PageRange>(), Err("page numbers start at 1"));
+        assert_eq!("a-2".parse::<PageRange>(), Err("page numbers start at 1"));
+        assert_eq!(
+            "5-2".parse::<PageRange>(),
+            Err("page range must not end before it starts")
+        );
+    }
+
+    #[test]
+    fn test_page_range_contains() {
+        let nr = |n| NonZeroUsize::new(n).unwrap();
+        let range: PageRange = "2-4".parse().unwrap();
+        assert!(!range.contains(nr(1)));
+        assert!(range.contains(nr(2)));
+        assert!(range.contains(nr(4)));
+        assert!(!range.contains(nr(5)));
+        assert!("3-".parse::<PageRange>().unwrap().contains(nr(100)));
+    }
+}
@@ -0,0 +1,96 @@
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+
+use comemo::Track;
+use ecow::eco_format;
+use typst::diag::{bail, SourceResult, StrResult};
+use typst::eval::{Route, Tracer};
+use typst::geom::Color;
+use typst::World;
+
+use crate::args::{CompileCommand, OutputFormat};
+use crate::compile::print_diagnostics;
+use crate::set_failed;
+use crate::world::SystemWorld;
+
+/// The stages of a compilation that are timed.
+const STAGES: [&str; 4] = ["parse", "eval", "layout", "export"];
+
+/// Compile and export the document the given number of times and print how
+/// long each stage took.
+pub fn bench(
+    world: &SystemWorld,
+    command: &CompileCommand,
+    runs: NonZeroUsize,
+) -> StrResult<()> {
+    let main = world.source(world.main()).map_err(|err| err.to_string())?;
+    let format = command.output_format()?;
+
+    let mut timings = vec![[Duration::ZERO; STAGES.len()]; runs.get()];
+    for timing in &mut timings {
+        // Evict all memoized results, so that every run does the full work.
+        comemo::evict(0);
+        if let Err(errors) = run(world, &main, format, command, timing) {
+            set_failed();
+            print_diagnostics(world, &errors, &[], command.common.diagnostic_format)
+                .map_err(|err| eco_format!("failed to print diagnostics ({err})"))?;
+            bail!("compilation failed");
+        }
+    }
+
+    println!("{:<8}{:>12}{:>12}{:>12}", "stage", "min", "mean", "max");
+    for (i, stage) in STAGES.iter().enumerate() {
+        let durations = timings.iter().map(|timing| timing[i]);
+        let min = durations.clone().min().unwrap_or_default();
+        let max = durations.clone().max().unwrap_or_default();
+        let mean = durations.sum::<Duration>() / runs.get() as u32;
+        println!("{stage:<8}{min:>12.2?}{mean:>12.2?}{max:>12.2?}");
+    }
+
+    Ok(())
+}
+
+/// Perform one run, writing the duration of each stage into `timing`.
+fn run(
+    world: &SystemWorld,
+    main: &typst::syntax::Source,
+    format: OutputFormat,
+    command: &CompileCommand,
+    timing: &mut [Duration; STAGES.len()],
+) -> SourceResult<()> {
+    let world = (world as &dyn World).track();
+    let route = Route::default();
+    let mut tracer = Tracer::new();
+
+    let start = Instant::now();
+    typst::syntax::parse(main.text());
+    timing[0] = start.elapsed();
+
+    let start = Instant::now();
+    let module = typst::eval::eval(world, route.track(), tracer.track_mut(), main)?;
+    timing[1] = start.elapsed();
+
+    let start = Instant::now();
+    let document = typst::model::layout(world, tracer.track_mut(), &module.content())?;
+    timing[2] = start.elapsed();
+
+    let start = Instant::now();
+    match format {
+        OutputFormat::Pdf => {
+            typst_pdf::pdf(&document, None, None);
+        }
+        OutputFormat::Png => {
+            for page in &document.pages {
+                typst_render::render(page, command.ppi / 72.0, Color::WHITE);
+            }
+        }
+        OutputFormat::Svg => {
+            for page in &document.pages {
+                typst_svg::svg(page);
+            }
+        }
+    }
+    timing[3] = start.elapsed();
+
+    Ok(())
+}
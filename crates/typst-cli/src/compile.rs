@@ -1,4 +1,5 @@
 use std::fs;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 
 use chrono::{Datelike, Timelike};
@@ -6,13 +7,14 @@ use codespan_reporting::diagnostic::{Diagnostic, Label};
 use codespan_reporting::term::{self, termcolor};
 use termcolor::{ColorChoice, StandardStream};
 use typst::diag::{bail, Severity, SourceDiagnostic, StrResult};
-use typst::doc::Document;
+use typst::doc::{Document, Frame};
 use typst::eval::{eco_format, Datetime, Tracer};
 use typst::geom::Color;
 use typst::syntax::{FileId, Source, Span};
 use typst::{World, WorldExt};
 
-use crate::args::{CompileCommand, DiagnosticFormat, OutputFormat};
+use crate::args::{CompileCommand, DiagnosticFormat, Lint, OutputFormat};
+use crate::lint;
 use crate::watch::Status;
 use crate::world::SystemWorld;
 use crate::{color_stream, set_failed};
@@ -34,6 +36,13 @@ impl CompileCommand {
         })
     }
 
+    /// Whether the given page (counting from one) should be exported.
+    pub fn exports_page(&self, page: NonZeroUsize) -> bool {
+        self.pages
+            .as_ref()
+            .map_or(true, |ranges| ranges.iter().any(|range| range.contains(page)))
+    }
+
     /// The format to use for generated output, either specified by the user or inferred from the extension.
     ///
     /// Will return `Err` if the format was not specified and could not be inferred.
@@ -56,6 +65,9 @@ impl CompileCommand {
 /// Execute a compilation command.
 pub fn compile(mut command: CompileCommand) -> StrResult<()> {
     let mut world = SystemWorld::new(&command.common)?;
+    if let Some(runs) = command.bench_internal {
+        return crate::bench::bench(&world, &command, runs);
+    }
     compile_once(&mut world, &mut command, false)?;
     Ok(())
 }
@@ -79,9 +91,31 @@ pub fn compile_once(
     // Ensure that the main file is present.
     world.source(world.main()).map_err(|err| err.to_string())?;
 
+    let lints = lint::enabled(world, &command.lints)?;
     let mut tracer = Tracer::new();
-    let result = typst::compile(world, &mut tracer);
-    let warnings = tracer.warnings();
+    if command.strict {
+        tracer.strict();
+    }
+    if lints.contains(&Lint::UnusedBindings) {
+        tracer.track_bindings();
+    }
+
+    let mut result = typst::compile(world, &mut tracer);
+    let unused = tracer.unused_bindings();
+    let mut warnings = tracer.warnings();
+    if let Ok(document) = &result {
+        warnings.extend(lint::lint(world, document, &unused, &lints));
+    }
+
+    // In strict mode, any warning fails the compilation.
+    if command.strict && !warnings.is_empty() {
+        let mut errors = result.err().unwrap_or_default();
+        errors.extend(std::mem::take(&mut warnings).into_iter().map(|mut warning| {
+            warning.severity = Severity::Error;
+            warning
+        }));
+        result = Err(errors);
+    }
 
     match result {
         // Export the PDF / PNG.
@@ -146,15 +180,53 @@ fn export(
     }
 }
 
-/// Export to a PDF.
+/// Export to one or multiple PDFs.
 fn export_pdf(
     document: &Document,
     command: &CompileCommand,
     world: &SystemWorld,
 ) -> StrResult<()> {
     let ident = world.input().to_string_lossy();
-    let buffer = typst_pdf::pdf(document, Some(&ident), now());
     let output = command.output();
+    let options = typst_pdf::PdfOptions {
+        output_intent: command
+            .output_intent
+            .as_ref()
+            .map(|path| {
+                let data = fs::read(path)
+                    .map_err(|err| eco_format!("failed to read ICC profile ({err})"))?;
+                typst_pdf::OutputIntent::new(&data)
+            })
+            .transpose()?,
+        signature_placeholder: command.signature_placeholder,
+        // Pages are selected after layout, so that page numbers and
+        // references still reflect the full document.
+        pages: command.pages.is_some().then(|| {
+            selected_pages(document, command)
+                .map(|(i, _)| page_number(i))
+                .collect()
+        }),
+    };
+    let string = output.to_str().unwrap_or_default();
+
+    // With a `{n}` numbering, each page is written to its own file. Links to
+    // other pages are left out, as they would lead into other files.
+    if string.contains("{n}") {
+        for (i, _) in selected_pages(document, command) {
+            let options = typst_pdf::PdfOptions {
+                pages: Some(vec![page_number(i)]),
+                ..options.clone()
+            };
+            let buffer =
+                typst_pdf::pdf_with_options(document, Some(&ident), now(), &options);
+            let path = numbered_path(string, i, document.pages.len());
+            fs::write(path, buffer)
+                .map_err(|err| eco_format!("failed to write PDF file ({err})"))?;
+        }
+        return Ok(());
+    }
+
+    let buffer = typst_pdf::pdf_with_options(document, Some(&ident), now(), &options);
     fs::write(output, buffer)
         .map_err(|err| eco_format!("failed to write PDF file ({err})"))?;
     Ok(())
@@ -191,20 +263,16 @@ fn export_image(
     let output = command.output();
     let string = output.to_str().unwrap_or_default();
     let numbered = string.contains("{n}");
-    if !numbered && document.pages.len() > 1 {
+    if !numbered && selected_pages(document, command).count() > 1 {
         bail!("cannot export multiple images without `{{n}}` in output path");
     }
 
-    // Find a number width that accommodates all pages. For instance, the
-    // first page should be numbered "001" if there are between 100 and
-    // 999 pages.
-    let width = 1 + document.pages.len().checked_ilog10().unwrap_or(0) as usize;
     let mut storage;
 
     let cache = world.export_cache();
-    for (i, frame) in document.pages.iter().enumerate() {
+    for (i, frame) in selected_pages(document, command) {
         let path = if numbered {
-            storage = string.replace("{n}", &format!("{:0width$}", i + 1));
+            storage = numbered_path(string, i, document.pages.len());
             Path::new(&storage)
         } else {
             output.as_path()
@@ -236,6 +304,32 @@ fn export_image(
     Ok(())
 }
 
+/// Fill in the `{n}` in an output path with the number of the page at the
+/// given index. The number is padded with zeros to a width that accommodates
+/// all pages. For instance, the first page is numbered "001" if there are
+/// between 100 and 999 pages.
+fn numbered_path(template: &str, index: usize, pages: usize) -> String {
+    let width = 1 + pages.checked_ilog10().unwrap_or(0) as usize;
+    template.replace("{n}", &format!("{:0width$}", index + 1))
+}
+
+/// The number of the page at the given index.
+fn page_number(index: usize) -> NonZeroUsize {
+    NonZeroUsize::new(index + 1).unwrap()
+}
+
+/// The pages selected for export, along with their indices in the document.
+fn selected_pages<'a>(
+    document: &'a Document,
+    command: &'a CompileCommand,
+) -> impl Iterator<Item = (usize, &'a Frame)> + 'a {
+    document
+        .pages
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| command.exports_page(page_number(*i)))
+}
+
 /// Opens the given file using:
 /// - The default file viewer if `open` is `None`.
 /// - The given viewer provided by `open` if it is `Some`.
@@ -267,7 +361,7 @@ pub fn print_diagnostics(
     }
 
     for diagnostic in warnings.iter().chain(errors) {
-        let diag = match diagnostic.severity {
+        let mut diag = match diagnostic.severity {
             Severity::Error => Diagnostic::error(),
             Severity::Warning => Diagnostic::warning(),
         }
@@ -279,7 +373,19 @@ pub fn print_diagnostics(
                 .map(|e| (eco_format!("hint: {e}")).into())
                 .collect(),
         )
-        .with_labels(label(world, diagnostic.span).into_iter().collect());
+        .with_labels(
+            label(world, diagnostic.span)
+                .into_iter()
+                .chain(diagnostic.related.iter().filter_map(|related| {
+                    let id = related.span.id()?;
+                    let range = world.range(related.span)?;
+                    Some(Label::secondary(id, range).with_message(related.v.clone()))
+                }))
+                .collect(),
+        );
+
+        // Shown as `error[E0101]: ...`.
+        diag.code = diagnostic.code.map(String::from);
 
         term::emit(&mut w, &config, world, &diag)?;
 
@@ -365,3 +471,17 @@ impl<'a> codespan_reporting::files::Files<'a> for SystemWorld {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbered_path() {
+        assert_eq!(numbered_path("page-{n}.png", 0, 1), "page-1.png");
+        assert_eq!(numbered_path("page-{n}.png", 8, 9), "page-9.png");
+        assert_eq!(numbered_path("page-{n}.png", 0, 10), "page-01.png");
+        assert_eq!(numbered_path("page-{n}.pdf", 41, 120), "page-042.pdf");
+        assert_eq!(numbered_path("{n}/{n}.svg", 1, 2), "2/2.svg");
+    }
+}
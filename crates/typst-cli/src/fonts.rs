@@ -1,9 +1,9 @@
 use std::cell::OnceCell;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use fontdb::{Database, Source};
-use typst::diag::StrResult;
+use typst::diag::{bail, StrResult};
 use typst::font::{Font, FontBook, FontInfo, FontVariant};
 
 use crate::args::FontsCommand;
@@ -13,6 +13,10 @@ pub fn fonts(command: &FontsCommand) -> StrResult<()> {
     let mut searcher = FontSearcher::new();
     searcher.search(&command.font_paths);
 
+    if let Some(family) = &command.info {
+        return info(&searcher, family);
+    }
+
     for (name, infos) in searcher.book.families() {
         println!("{name}");
         if command.variants {
@@ -26,6 +30,40 @@ pub fn fonts(command: &FontsCommand) -> StrResult<()> {
     Ok(())
 }
 
+/// Print detailed information about each font of a family.
+fn info(searcher: &FontSearcher, family: &str) -> StrResult<()> {
+    let ids: Vec<_> = searcher.book.select_family(&family.to_lowercase()).collect();
+    if ids.is_empty() {
+        bail!("unknown font family: {family}");
+    }
+
+    for id in ids {
+        let Some(font) = searcher.fonts[id].get() else { continue };
+        let info = font.info();
+        let metrics = font.metrics();
+        let FontVariant { style, weight, stretch } = info.variant;
+        println!("{}", info.family);
+        if let Some(path) = searcher.fonts[id].path() {
+            println!("- Path: {}, Index: {}", path.display(), font.index());
+        }
+        println!("- Style: {style:?}, Weight: {weight:?}, Stretch: {stretch:?}");
+        println!("- Flags: {:?}", info.flags);
+        println!("- Coverage: {} codepoints", info.coverage.iter().count());
+        println!(
+            "- Metrics: Units per em: {}, Ascender: {:?}, Cap height: {:?}, \
+             X-height: {:?}, Descender: {:?}",
+            metrics.units_per_em,
+            metrics.ascender,
+            metrics.cap_height,
+            metrics.x_height,
+            metrics.descender,
+        );
+        println!("- Features: {}", font.features().join(", "));
+    }
+
+    Ok(())
+}
+
 /// Searches for fonts.
 pub struct FontSearcher {
     /// Metadata about all discovered fonts.
@@ -46,6 +84,11 @@ pub struct FontSlot {
 }
 
 impl FontSlot {
+    /// The path at which the font can be found, if it was not embedded.
+    pub fn path(&self) -> Option<&Path> {
+        Some(self.path.as_path()).filter(|path| !path.as_os_str().is_empty())
+    }
+
     /// Get the font for this slot.
     pub fn get(&self) -> Option<Font> {
         self.font
@@ -0,0 +1,31 @@
+use std::fs;
+
+use ecow::eco_format;
+use typst::diag::StrResult;
+
+use crate::args::FormatCommand;
+use crate::set_failed;
+
+/// Execute a formatting command.
+pub fn format(command: &FormatCommand) -> StrResult<()> {
+    for path in &command.inputs {
+        let text = fs::read_to_string(path)
+            .map_err(|err| eco_format!("failed to read {} ({err})", path.display()))?;
+
+        let formatted = typst::syntax::format(&typst::syntax::parse(&text));
+        if formatted == text {
+            continue;
+        }
+
+        if command.check {
+            eprintln!("{} is not formatted", path.display());
+            set_failed();
+        } else {
+            fs::write(path, formatted).map_err(|err| {
+                eco_format!("failed to write {} ({err})", path.display())
+            })?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ecow::eco_format;
+use typst::diag::{bail, StrResult};
+use typst::syntax::PackageSpec;
+
+use crate::args::InitCommand;
+use crate::package::prepare_package;
+
+/// The built-in templates, with the paths and contents of their files.
+const TEMPLATES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "letter",
+        &[
+            ("main.typ", include_str!("../templates/letter/main.typ")),
+            ("style.typ", include_str!("../templates/letter/style.typ")),
+        ],
+    ),
+    (
+        "report",
+        &[
+            ("main.typ", include_str!("../templates/report/main.typ")),
+            ("style.typ", include_str!("../templates/report/style.typ")),
+        ],
+    ),
+];
+
+/// Execute an initialization command.
+pub fn init(command: &InitCommand) -> StrResult<()> {
+    let dir = if command.template.starts_with('@') {
+        let spec: PackageSpec = command.template.parse()?;
+        let dir = target_dir(command, &spec.name)?;
+        let template = prepare_package(&spec)?.join("template");
+        if !template.is_dir() {
+            bail!("package {spec} does not contain a template");
+        }
+        copy_dir(&template, &dir)?;
+        dir
+    } else {
+        let Some((name, files)) =
+            TEMPLATES.iter().find(|(name, _)| *name == command.template)
+        else {
+            let names: Vec<_> = TEMPLATES.iter().map(|(name, _)| *name).collect();
+            bail!(
+                "unknown template `{}` (built-in templates are {})",
+                command.template,
+                names.join(", ")
+            );
+        };
+
+        let dir = target_dir(command, name)?;
+        for (path, text) in *files {
+            write(&dir.join(path), text.as_bytes())?;
+        }
+        dir
+    };
+
+    // Fonts can be passed with `--font-path fonts` and assets are read
+    // relative to the main file.
+    for sub in ["fonts", "assets"] {
+        create_dir(&dir.join(sub))?;
+    }
+
+    println!("Created a new project in {}", dir.display());
+    println!(
+        "Compile it with `typst compile --font-path fonts main.typ` in that directory"
+    );
+    Ok(())
+}
+
+/// Determine and create the project directory, which must not exist yet or be
+/// empty.
+fn target_dir(command: &InitCommand, name: &str) -> StrResult<PathBuf> {
+    let dir = command.dir.clone().unwrap_or_else(|| name.into());
+    if fs::read_dir(&dir).is_ok_and(|mut entries| entries.next().is_some()) {
+        bail!("project directory {} already exists and is not empty", dir.display());
+    }
+    create_dir(&dir)?;
+    Ok(dir)
+}
+
+/// Recursively copy the contents of a directory into another one.
+fn copy_dir(src: &Path, dest: &Path) -> StrResult<()> {
+    let entries = fs::read_dir(src)
+        .map_err(|err| eco_format!("failed to read {} ({err})", src.display()))?;
+
+    for entry in entries {
+        let entry = entry
+            .map_err(|err| eco_format!("failed to read {} ({err})", src.display()))?;
+        let target = dest.join(entry.file_name());
+        if entry.path().is_dir() {
+            create_dir(&target)?;
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            let data = fs::read(entry.path()).map_err(|err| {
+                eco_format!("failed to read {} ({err})", entry.path().display())
+            })?;
+            write(&target, &data)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a directory and its parents.
+fn create_dir(path: &Path) -> StrResult<()> {
+    fs::create_dir_all(path)
+        .map_err(|err| eco_format!("failed to create {} ({err})", path.display()))
+}
+
+/// Write a file.
+fn write(path: &Path, data: &[u8]) -> StrResult<()> {
+    fs::write(path, data)
+        .map_err(|err| eco_format!("failed to write {} ({err})", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use typst::eval::Tracer;
+
+    use super::*;
+    use crate::args::{DiagnosticFormat, SharedArgs};
+    use crate::world::SystemWorld;
+
+    /// Initialize the given template into a directory.
+    fn run(template: &str, dir: &Path) -> StrResult<()> {
+        init(&InitCommand { template: template.into(), dir: Some(dir.into()) })
+    }
+
+    #[test]
+    fn test_init_compiles_templates() {
+        let temp = tempfile::tempdir().unwrap();
+        for (name, _) in TEMPLATES {
+            let dir = temp.path().join(name);
+            run(name, &dir).unwrap();
+            assert!(dir.join("fonts").is_dir());
+            assert!(dir.join("assets").is_dir());
+
+            let args = SharedArgs {
+                input: dir.join("main.typ"),
+                root: None,
+                font_paths: vec![dir.join("fonts")],
+                diagnostic_format: DiagnosticFormat::Human,
+            };
+            let world = SystemWorld::new(&args).unwrap();
+            let document = typst::compile(&world, &mut Tracer::new())
+                .unwrap_or_else(|_| panic!("template `{name}` failed to compile"));
+            assert!(!document.pages.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_init_refuses_non_empty_dir() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("main.typ");
+        fs::write(&path, "Mine").unwrap();
+
+        let err = run("letter", temp.path()).unwrap_err();
+        assert!(err.contains("already exists and is not empty"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "Mine");
+        assert!(!temp.path().join("style.typ").exists());
+    }
+
+    #[test]
+    fn test_init_into_empty_dir() {
+        let temp = tempfile::tempdir().unwrap();
+        run("report", temp.path()).unwrap();
+        assert!(temp.path().join("main.typ").is_file());
+    }
+
+    #[test]
+    fn test_init_unknown_template() {
+        let temp = tempfile::tempdir().unwrap();
+        let err = run("thesis", &temp.path().join("thesis")).unwrap_err();
+        assert_eq!(
+            err,
+            "unknown template `thesis` (built-in templates are letter, report)"
+        );
+    }
+}
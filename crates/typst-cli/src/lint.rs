@@ -0,0 +1,380 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+
+use clap::ValueEnum;
+use ecow::{eco_format, EcoString};
+use typst::diag::{bail, warning, SourceDiagnostic, StrResult};
+use typst::doc::Document;
+use typst::model::{Introspector, NativeElement, Selector, StyleChain};
+use typst::syntax::{ast, LinkedNode, Span, SyntaxKind};
+use typst::World;
+use typst_library::meta::HeadingElem;
+
+use crate::args::Lint;
+use crate::world::SystemWorld;
+
+/// Determine the lints to run: Those given on the command line and those
+/// enabled in the `[lints]` table of the project's `typst.toml`, like this:
+///
+/// ```toml
+/// [lints]
+/// double-spaces = true
+/// todo = false
+/// ```
+pub fn enabled(world: &SystemWorld, flags: &[Lint]) -> StrResult<Vec<Lint>> {
+    let settings = match fs::read_to_string(world.root().join("typst.toml")) {
+        Ok(text) => settings(&text)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+        Err(err) => bail!("failed to read typst.toml ({err})"),
+    };
+
+    let mut lints = flags.to_vec();
+    for (lint, enabled) in settings {
+        if enabled && !lints.contains(&lint) {
+            lints.push(lint);
+        }
+    }
+
+    Ok(lints)
+}
+
+/// Parse the `[lints]` table of a `typst.toml` file, which enables or
+/// disables lints by name.
+fn settings(text: &str) -> StrResult<Vec<(Lint, bool)>> {
+    let table: toml::Table = text
+        .parse()
+        .map_err(|err| eco_format!("failed to parse typst.toml ({err})"))?;
+
+    let Some(lints) = table.get("lints") else { return Ok(vec![]) };
+    let Some(lints) = lints.as_table() else {
+        bail!("`lints` in typst.toml must be a table");
+    };
+
+    lints
+        .iter()
+        .map(|(name, value)| {
+            let Ok(lint) = Lint::from_str(name, false) else {
+                bail!("unknown lint `{name}` in typst.toml");
+            };
+            let Some(enabled) = value.as_bool() else {
+                bail!("lint `{name}` in typst.toml must be set to true or false");
+            };
+            Ok((lint, enabled))
+        })
+        .collect()
+}
+
+/// Run the given lints over the project's sources and the compiled document.
+///
+/// The unused bindings are those that the tracer found during evaluation.
+pub fn lint(
+    world: &mut SystemWorld,
+    document: &Document,
+    unused: &[(Span, bool)],
+    lints: &[Lint],
+) -> Vec<SourceDiagnostic> {
+    if lints.is_empty() {
+        return vec![];
+    }
+
+    let mut linter = Linter {
+        lints,
+        warnings: vec![],
+        labels: vec![],
+        refs: HashSet::new(),
+    };
+
+    for id in world.project_sources() {
+        let source = world.lookup(id);
+        linter.syntax(&LinkedNode::new(source.root()));
+    }
+
+    if lints.contains(&Lint::UnusedBindings) {
+        linter.unused_bindings(world, unused);
+    }
+
+    if lints.contains(&Lint::UnusedLabels) {
+        for (label, span) in std::mem::take(&mut linter.labels) {
+            if !linter.refs.contains(&label) {
+                linter.warn(span, &format!("label `<{label}>` is never referenced"));
+            }
+        }
+    }
+
+    if lints.contains(&Lint::StraightQuotes) {
+        linter.straight_quotes(world, document);
+    }
+
+    if lints.contains(&Lint::HeadingLevels) {
+        linter.heading_levels(document);
+    }
+
+    linter.warnings
+}
+
+/// Collects the warnings of the lints.
+struct Linter<'a> {
+    /// The enabled lints.
+    lints: &'a [Lint],
+    /// The warnings found so far.
+    warnings: Vec<SourceDiagnostic>,
+    /// All labels in the sources.
+    labels: Vec<(EcoString, Span)>,
+    /// The targets of all references and labels used as values in the sources.
+    refs: HashSet<EcoString>,
+}
+
+impl Linter<'_> {
+    /// Lint a syntax node and its children.
+    fn syntax(&mut self, node: &LinkedNode) {
+        match node.kind() {
+            SyntaxKind::Space
+                if self.lints.contains(&Lint::DoubleSpaces)
+                    && node.parent_kind() == Some(SyntaxKind::Markup)
+                    && node.text().contains("  ")
+                    && !node.text().contains('\n') =>
+            {
+                self.warn(node.span(), "consecutive spaces collapse into one");
+            }
+            SyntaxKind::LineComment | SyntaxKind::BlockComment | SyntaxKind::Text
+                if self.lints.contains(&Lint::Todo) && node.text().contains("TODO") =>
+            {
+                self.warn(node.span(), "found TODO marker");
+            }
+            SyntaxKind::Label => {
+                // Labels in markup are attached to elements, while labels in
+                // code are values, like in `link(<intro>)` or `query(<x>)`.
+                if let Some(label) = node.cast::<ast::Label>() {
+                    if node.parent_kind() == Some(SyntaxKind::Markup) {
+                        self.labels.push((label.get().into(), node.span()));
+                    } else {
+                        self.refs.insert(label.get().into());
+                    }
+                }
+            }
+            SyntaxKind::Ref => {
+                if let Some(reference) = node.cast::<ast::Ref>() {
+                    self.refs.insert(reference.target().into());
+                }
+            }
+            _ => {}
+        }
+
+        for child in node.children() {
+            self.syntax(&child);
+        }
+    }
+
+    /// Report the let bindings and imported items that were never read
+    /// during evaluation.
+    ///
+    /// Top-level definitions of files other than the main file are skipped,
+    /// since other files may import them.
+    fn unused_bindings(&mut self, world: &SystemWorld, unused: &[(Span, bool)]) {
+        for &(span, top) in unused {
+            let Some(id) = span.id() else { continue };
+            if top && id != world.main() {
+                continue;
+            }
+
+            let Ok(source) = world.source(id) else { continue };
+            let Some(node) = source.find(span) else { continue };
+            let name = node.text();
+            if name.starts_with('_') {
+                continue;
+            }
+
+            let import = std::iter::successors(node.parent(), |&node| node.parent())
+                .any(|node| node.kind() == SyntaxKind::ModuleImport);
+            let kind = if import { "import" } else { "variable" };
+            self.warn(span, &format!("unused {kind} `{name}`"));
+        }
+    }
+
+    /// Find smart quotes that ended up straight in the document.
+    fn straight_quotes(&mut self, world: &SystemWorld, document: &Document) {
+        for page in &document.pages {
+            for (_, run) in page.text_runs() {
+                for (i, c) in run.text.char_indices() {
+                    if !matches!(c, '"' | '\'') {
+                        continue;
+                    }
+
+                    let Some((span, _)) = run.span_at(i) else { continue };
+                    let Some(id) = span.id() else { continue };
+                    let Ok(source) = world.source(id) else { continue };
+                    if source
+                        .find(span)
+                        .is_some_and(|node| node.kind() == SyntaxKind::SmartQuote)
+                    {
+                        self.warn(span, "quote is straight because smart quotes are off");
+                    }
+                }
+            }
+        }
+    }
+
+    /// Find headings that skip a level.
+    fn heading_levels(&mut self, document: &Document) {
+        let introspector = Introspector::new(&document.pages);
+        let mut previous = 0;
+        for heading in introspector.query(&Selector::Elem(HeadingElem::elem(), None)) {
+            let Some(heading) = heading.to::<HeadingElem>() else { continue };
+            let level = heading.level(StyleChain::default()).get();
+            if previous > 0 && level > previous + 1 {
+                self.warn(
+                    heading.span(),
+                    &format!("heading skips from level {previous} to level {level}"),
+                );
+            }
+            previous = level;
+        }
+    }
+
+    /// Report a warning.
+    fn warn(&mut self, span: Span, message: &str) {
+        self.warnings.push(warning!(span, "{message}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typst::eval::Tracer;
+
+    use super::*;
+    use crate::world::temp_world;
+
+    /// Compile the given files, of which the first is the main file, and
+    /// return the messages of the warnings the lints report.
+    fn run(files: &[(&str, &str)], lints: &[Lint]) -> Vec<EcoString> {
+        let (_dir, mut world) = temp_world(files);
+        let mut tracer = Tracer::new();
+        tracer.track_bindings();
+        let document = typst::compile(&world, &mut tracer).unwrap();
+        let unused = tracer.unused_bindings();
+        lint(&mut world, &document, &unused, lints)
+            .into_iter()
+            .map(|warning| warning.message)
+            .collect()
+    }
+
+    #[track_caller]
+    fn test(text: &str, kind: Lint, expected: &[&str]) {
+        assert_eq!(run(&[("main.typ", text)], &[kind]), expected);
+    }
+
+    #[test]
+    fn test_double_spaces() {
+        let message = "consecutive spaces collapse into one";
+        test("One  two", Lint::DoubleSpaces, &[message]);
+        test("One two\n\n  Three", Lint::DoubleSpaces, &[]);
+        test("#let x  = \"a  b\"\n#x", Lint::DoubleSpaces, &[]);
+    }
+
+    #[test]
+    fn test_straight_quotes() {
+        let message = "quote is straight because smart quotes are off";
+        let off = "#set smartquote(enabled: false)\n\"Hi\" and 'you'";
+        test(off, Lint::StraightQuotes, &[message; 4]);
+        test("\"Hi\" and 'you'", Lint::StraightQuotes, &[]);
+        test("#set smartquote(enabled: false)\n`\"code\"`", Lint::StraightQuotes, &[]);
+    }
+
+    #[test]
+    fn test_heading_levels() {
+        test(
+            "= A\n=== B",
+            Lint::HeadingLevels,
+            &["heading skips from level 1 to level 3"],
+        );
+        test("= A\n== B\n=== C\n= D\n== E", Lint::HeadingLevels, &[]);
+    }
+
+    #[test]
+    fn test_unused_labels() {
+        let numbered = "#set heading(numbering: \"1.\")\n";
+        let text = format!("{numbered}= A <a>\n= B <b>\n@a");
+        test(&text, Lint::UnusedLabels, &["label `<b>` is never referenced"]);
+        let text = format!("{numbered}= A <a>\n= B <b>\n@a #link(<b>)[B]");
+        test(&text, Lint::UnusedLabels, &[]);
+    }
+
+    #[test]
+    fn test_todo() {
+        let message = "found TODO marker";
+        test("// TODO: Fix.\nTODO later", Lint::Todo, &[message; 2]);
+        test("/* A todo list. */\nTodo list", Lint::Todo, &[]);
+    }
+
+    #[test]
+    fn test_lints_are_opt_in() {
+        let text = "= A  B <a>\n=== C\n// TODO\n#let x = 1";
+        assert!(run(&[("main.typ", text)], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_lint_settings() {
+        let text =
+            "[package]\nname = \"x\"\n\n[lints]\ntodo = true\ndouble-spaces = false";
+        assert_eq!(
+            settings(text).unwrap(),
+            [(Lint::Todo, true), (Lint::DoubleSpaces, false)]
+        );
+        assert!(settings("[package]\nname = \"x\"").unwrap().is_empty());
+        assert_eq!(
+            settings("[lints]\nspelling = true").unwrap_err(),
+            "unknown lint `spelling` in typst.toml"
+        );
+        assert_eq!(
+            settings("[lints]\ntodo = \"yes\"").unwrap_err(),
+            "lint `todo` in typst.toml must be set to true or false"
+        );
+        assert_eq!(
+            settings("lints = 1").unwrap_err(),
+            "`lints` in typst.toml must be a table"
+        );
+    }
+
+    #[test]
+    fn test_lint_settings_enable_lints() {
+        let files = [("main.typ", "TODO  now"), ("typst.toml", "[lints]\ntodo = true")];
+        let (_dir, world) = temp_world(&files);
+        let lints = enabled(&world, &[Lint::DoubleSpaces]).unwrap();
+        assert_eq!(lints, [Lint::DoubleSpaces, Lint::Todo]);
+    }
+
+    #[test]
+    fn test_unused_bindings() {
+        let unused = Lint::UnusedBindings;
+        test("#let a = 1\n#let b = 2\n#a", unused, &["unused variable `b`"]);
+        test("#let _unused = 1", unused, &[]);
+        test("#let (a, b) = (1, 2)\n#a", unused, &["unused variable `b`"]);
+        test("#for i in range(3) { let sq = i * i; [#sq] }", unused, &[]);
+        test("#let helper() = 1", unused, &["unused variable `helper`"]);
+
+        // Shadowed bindings are distinct from the ones shadowing them.
+        test("#let x = 1\n#let x = 2\n#x", unused, &["unused variable `x`"]);
+        test("#let x = 1\n#{ let x = 2; x }", unused, &["unused variable `x`"]);
+
+        // Capturing a variable in a closure reads it, even if the closure
+        // is never called.
+        test("#let offset = 1\n#let f(x) = x + offset\n#f(1)", unused, &[]);
+        test("#let offset = 1\n#let _f() = offset", unused, &[]);
+
+        // Code that is never evaluated is not checked.
+        test("#if false { let x = 1 }", unused, &[]);
+    }
+
+    #[test]
+    fn test_unused_imports() {
+        let main = "#import \"lib.typ\": a, b, f\n#a\n#f()";
+        let lib = "#let a = 1\n#let b = 2\n#let c = 3\n#let f() = { let tmp = 1; 2 }";
+        let files = [("main.typ", main), ("lib.typ", lib)];
+        let warnings = run(&files, &[Lint::UnusedBindings]);
+
+        // The top-level bindings of the library are not checked since other
+        // files may import them, but the ones in its functions are.
+        assert_eq!(warnings, ["unused import `b`", "unused variable `tmp`"]);
+    }
+}
@@ -1,9 +1,14 @@
 mod args;
+mod bench;
 mod compile;
 mod download;
 mod fonts;
+mod format;
+mod init;
+mod lint;
 mod package;
 mod query;
+mod stats;
 mod tracing;
 #[cfg(feature = "self-update")]
 mod update;
@@ -16,10 +21,13 @@ use std::process::ExitCode;
 
 use clap::Parser;
 use codespan_reporting::term::{self, termcolor};
+use ecow::eco_format;
 use once_cell::sync::Lazy;
+use serde::Serialize;
 use termcolor::{ColorChoice, WriteColor};
+use typst::diag::StrResult;
 
-use crate::args::{CliArguments, Command};
+use crate::args::{CliArguments, Command, SerializationFormat};
 
 thread_local! {
     /// The CLI's exit code.
@@ -42,8 +50,11 @@ fn main() -> ExitCode {
     let res = match &ARGS.command {
         Command::Compile(command) => crate::compile::compile(command.clone()),
         Command::Watch(command) => crate::watch::watch(command.clone()),
+        Command::Init(command) => crate::init::init(command),
         Command::Query(command) => crate::query::query(command),
+        Command::Stats(command) => crate::stats::stats(command),
         Command::Fonts(command) => crate::fonts::fonts(command),
+        Command::Format(command) => crate::format::format(command),
         Command::Update(command) => crate::update::update(command),
     };
 
@@ -81,6 +92,18 @@ fn color_stream() -> termcolor::StandardStream {
     })
 }
 
+/// Serialize data to the output format.
+fn serialize(data: &impl Serialize, format: SerializationFormat) -> StrResult<String> {
+    match format {
+        SerializationFormat::Json => {
+            serde_json::to_string_pretty(data).map_err(|e| eco_format!("{e}"))
+        }
+        SerializationFormat::Yaml => {
+            serde_yaml::to_string(&data).map_err(|e| eco_format!("{e}"))
+        }
+    }
+}
+
 /// Used by `args.rs`.
 fn typst_version() -> &'static str {
     env!("TYPST_VERSION")
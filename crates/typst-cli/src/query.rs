@@ -1,13 +1,13 @@
 use comemo::Track;
-use serde::Serialize;
 use typst::diag::{bail, StrResult};
 use typst::eval::{eval_string, EvalMode, Tracer};
 use typst::model::Introspector;
 use typst::World;
 use typst_library::prelude::*;
 
-use crate::args::{QueryCommand, SerializationFormat};
+use crate::args::QueryCommand;
 use crate::compile::print_diagnostics;
+use crate::serialize;
 use crate::set_failed;
 use crate::world::SystemWorld;
 
@@ -103,15 +103,3 @@ fn format(elements: Vec<Content>, command: &QueryCommand) -> StrResult<String> {
         serialize(&mapped, command.format)
     }
 }
-
-/// Serialize data to the output format.
-fn serialize(data: &impl Serialize, format: SerializationFormat) -> StrResult<String> {
-    match format {
-        SerializationFormat::Json => {
-            serde_json::to_string_pretty(data).map_err(|e| eco_format!("{e}"))
-        }
-        SerializationFormat::Yaml => {
-            serde_yaml::to_string(&data).map_err(|e| eco_format!("{e}"))
-        }
-    }
-}
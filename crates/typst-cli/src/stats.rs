@@ -0,0 +1,157 @@
+use std::collections::BTreeSet;
+
+use ecow::{eco_format, EcoString};
+use serde::Serialize;
+use typst::diag::StrResult;
+use typst::doc::{Document, Frame, FrameItem};
+use typst::eval::Tracer;
+use typst::World;
+
+use crate::args::StatsCommand;
+use crate::compile::print_diagnostics;
+use crate::serialize;
+use crate::set_failed;
+use crate::world::SystemWorld;
+
+/// Execute a stats command.
+pub fn stats(command: &StatsCommand) -> StrResult<()> {
+    let mut world = SystemWorld::new(&command.common)?;
+    tracing::info!("Starting statistics");
+
+    // Reset everything and ensure that the main file is present.
+    world.reset();
+    world.source(world.main()).map_err(|err| err.to_string())?;
+
+    let mut tracer = Tracer::new();
+    let result = typst::compile(&world, &mut tracer);
+    let warnings = tracer.warnings();
+
+    match result {
+        // Compute and print the statistics.
+        Ok(document) => {
+            let serialized = serialize(&Stats::new(&document), command.format)?;
+            println!("{serialized}");
+            print_diagnostics(&world, &[], &warnings, command.common.diagnostic_format)
+                .map_err(|err| eco_format!("failed to print diagnostics ({err})"))?;
+        }
+
+        // Print diagnostics.
+        Err(errors) => {
+            set_failed();
+            print_diagnostics(
+                &world,
+                &errors,
+                &warnings,
+                command.common.diagnostic_format,
+            )
+            .map_err(|err| eco_format!("failed to print diagnostics ({err})"))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Statistics about a compiled document.
+#[derive(Debug, Default, Serialize)]
+struct Stats {
+    /// The number of pages.
+    pages: usize,
+    /// The number of words in the laid out text.
+    words: usize,
+    /// The number of placed images.
+    images: usize,
+    /// The families of all fonts used for text.
+    fonts: BTreeSet<EcoString>,
+    /// The size of the document exported as PDF, in bytes.
+    pdf_size: usize,
+    /// A hash of the document's output that ignores timestamps and changes
+    /// to the sources that don't affect the output.
+    hash: String,
+}
+
+impl Stats {
+    /// Gather the statistics of a document.
+    fn new(document: &Document) -> Self {
+        let mut stats = Self {
+            pages: document.pages.len(),
+            hash: format!("{:032x}", document.content_hash()),
+            ..Self::default()
+        };
+        for page in &document.pages {
+            stats.visit(page);
+            stats.words += page
+                .text()
+                .split_whitespace()
+                .filter(|word| word.chars().any(char::is_alphanumeric))
+                .count();
+        }
+
+        // The PDF is only exported into memory to measure it.
+        stats.pdf_size = typst_pdf::pdf(document, None, None).len();
+        stats
+    }
+
+    /// Collect the images and fonts in a frame.
+    fn visit(&mut self, frame: &Frame) {
+        for (_, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => self.visit(&group.frame),
+                FrameItem::Text(text) => {
+                    self.fonts.insert(text.font.info().family.as_str().into());
+                }
+                FrameItem::Image(..) => self.images += 1,
+                FrameItem::Shape(..) | FrameItem::Meta(..) => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::args::SerializationFormat;
+    use crate::world::temp_world;
+
+    /// Compile the given files, of which the first is the main file, and
+    /// gather the statistics of the resulting document.
+    fn run(files: &[(&str, &str)]) -> Stats {
+        let (_dir, world) = temp_world(files);
+        let document = typst::compile(&world, &mut Tracer::new()).unwrap();
+        Stats::new(&document)
+    }
+
+    #[test]
+    fn test_stats() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"/>"#;
+        let main = "\
+            Hello world, it's me -- 42!\n\
+            `code`\n\
+            #image(\"img.svg\", width: 10pt)\n\
+            #box(image(\"img.svg\", width: 10pt))\n\
+            #pagebreak()\n\
+            #rotate(10deg)[Two words]";
+
+        let stats = run(&[("main.typ", main), ("img.svg", svg)]);
+        assert_eq!(stats.pages, 2);
+        assert_eq!(stats.words, 8);
+        assert_eq!(stats.images, 2);
+        assert_eq!(
+            stats.fonts.iter().map(EcoString::as_str).collect::<Vec<_>>(),
+            ["DejaVu Sans Mono", "Linux Libertine"]
+        );
+        assert!(stats.pdf_size > 0);
+        assert_eq!(stats.hash.len(), 32);
+
+        let json = serialize(&stats, SerializationFormat::Json).unwrap();
+        assert!(json.contains("\"words\": 8"));
+    }
+
+    #[test]
+    fn test_stats_of_empty_document() {
+        let stats = run(&[("main.typ", "")]);
+        assert_eq!(stats.pages, 1);
+        assert_eq!(stats.words, 0);
+        assert_eq!(stats.images, 0);
+        assert!(stats.fonts.is_empty());
+    }
+}
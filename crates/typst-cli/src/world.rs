@@ -108,6 +108,17 @@ impl SystemWorld {
             .filter_map(|slot| slot.system_path(&self.root).ok())
     }
 
+    /// Return the ids of all source files of the project (excluding packages)
+    /// that the last compilation evaluated.
+    pub fn project_sources(&mut self) -> Vec<FileId> {
+        self.slots
+            .get_mut()
+            .values()
+            .filter(|slot| slot.source.accessed() && slot.id.package().is_none())
+            .map(|slot| slot.id)
+            .collect()
+    }
+
     /// Reset the compilation state in preparation of a new compilation.
     pub fn reset(&mut self) {
         for slot in self.slots.borrow_mut().values_mut() {
@@ -350,6 +361,26 @@ impl ExportCache {
     }
 }
 
+/// Write the given files into a temporary directory and create a world whose
+/// main file is the first one.
+#[cfg(test)]
+pub fn temp_world(files: &[(&str, &str)]) -> (tempfile::TempDir, SystemWorld) {
+    let dir = tempfile::tempdir().unwrap();
+    for (path, text) in files {
+        fs::write(dir.path().join(path), text).unwrap();
+    }
+
+    let args = SharedArgs {
+        input: dir.path().join(files[0].0),
+        root: None,
+        font_paths: vec![],
+        diagnostic_format: crate::args::DiagnosticFormat::Human,
+    };
+
+    let world = SystemWorld::new(&args).unwrap();
+    (dir, world)
+}
+
 /// Read a file.
 fn read(path: &Path) -> FileResult<Vec<u8>> {
     let f = |e| FileError::from_io(e, path);
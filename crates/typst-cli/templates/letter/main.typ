@@ -0,0 +1,15 @@
+#import "style.typ": letter
+
+#show: letter.with(
+  sender: [Jane Doe \ 1 Example Street \ Springfield],
+  recipient: [John Smith \ 2 Sample Road \ Shelbyville],
+  date: datetime.today().display("[day].[month].[year]"),
+  subject: [Your inquiry],
+)
+
+Dear John,
+
+#lorem(80)
+
+Kind regards, \
+Jane Doe
@@ -0,0 +1,26 @@
+// The look of the letter. Adjust page, fonts, and spacing here.
+#let letter(
+  sender: none,
+  recipient: none,
+  date: none,
+  subject: none,
+  body,
+) = {
+  set page(paper: "a4", margin: (top: 3cm, rest: 2.5cm))
+  set text(size: 11pt)
+  set par(justify: true)
+
+  align(right, sender)
+  v(1.5cm)
+  recipient
+  v(1cm)
+  align(right, date)
+
+  if subject != none {
+    v(0.5cm)
+    strong(subject)
+  }
+
+  v(0.5cm)
+  body
+}
@@ -0,0 +1,18 @@
+#import "style.typ": report
+
+#show: report.with(
+  title: "Report Title",
+  author: ("Jane Doe",),
+)
+
+= Introduction
+#lorem(60)
+
+== Background
+#lorem(40)
+
+= Results
+#lorem(60)
+
+// Put images into the assets folder and show them like this:
+// #figure(image("assets/chart.png"), caption: [A chart.])
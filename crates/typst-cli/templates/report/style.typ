@@ -0,0 +1,19 @@
+// The look of the report. Adjust page, fonts, and spacing here.
+#let report(title: "Untitled", author: (), body) = {
+  set document(title: title, author: author)
+  set page(numbering: "1", number-align: center)
+  set text(size: 11pt)
+  set par(justify: true)
+  set heading(numbering: "1.1")
+
+  align(center, {
+    text(size: 20pt, weight: "bold", title)
+    v(0.5em)
+    author.join(", ")
+  })
+
+  v(1cm)
+  outline()
+  pagebreak()
+  body
+}
@@ -5,6 +5,7 @@ use std::cmp::Ordering;
 use std::ops::{Div, Rem};
 
 use typst::eval::{Module, Scope};
+use typst::util::Rng;
 
 use crate::prelude::*;
 
@@ -53,6 +54,7 @@ fn module() -> Module {
     scope.define_func::<div_euclid>();
     scope.define_func::<rem_euclid>();
     scope.define_func::<quo>();
+    scope.define_func::<random>();
     scope.define("inf", f64::INFINITY);
     scope.define("nan", f64::NAN);
     scope.define("pi", std::f64::consts::PI);
@@ -135,7 +137,7 @@ pub fn pow(
     };
 
     if result.float().is_nan() {
-        bail!(span, "the result is not a real number")
+        bail!(error!(span, "the result is not a real number").with_code("E0201"))
     }
 
     Ok(result)
@@ -165,7 +167,7 @@ pub fn exp(
 
     let result = exponent.v.float().exp();
     if result.is_nan() {
-        bail!(span, "the result is not a real number")
+        bail!(error!(span, "the result is not a real number").with_code("E0201"))
     }
 
     Ok(result)
@@ -183,7 +185,8 @@ pub fn sqrt(
     value: Spanned<Num>,
 ) -> SourceResult<f64> {
     if value.v.float() < 0.0 {
-        bail!(value.span, "cannot take square root of negative number");
+        bail!(error!(value.span, "cannot take square root of negative number")
+            .with_code("E0201"));
     }
     Ok(value.v.float().sqrt())
 }
@@ -266,7 +269,7 @@ pub fn asin(
 ) -> SourceResult<Angle> {
     let val = value.v.float();
     if val < -1.0 || val > 1.0 {
-        bail!(value.span, "value must be between -1 and 1");
+        bail!(error!(value.span, "value must be between -1 and 1").with_code("E0201"));
     }
     Ok(Angle::rad(val.asin()))
 }
@@ -284,7 +287,7 @@ pub fn acos(
 ) -> SourceResult<Angle> {
     let val = value.v.float();
     if val < -1.0 || val > 1.0 {
-        bail!(value.span, "value must be between -1 and 1");
+        bail!(error!(value.span, "value must be between -1 and 1").with_code("E0201"));
     }
     Ok(Angle::rad(val.acos()))
 }
@@ -383,7 +386,7 @@ pub fn log(
 ) -> SourceResult<f64> {
     let number = value.v.float();
     if number <= 0.0 {
-        bail!(value.span, "value must be strictly positive")
+        bail!(error!(value.span, "value must be strictly positive").with_code("E0201"))
     }
 
     if !base.v.is_normal() {
@@ -401,7 +404,7 @@ pub fn log(
     };
 
     if result.is_infinite() || result.is_nan() {
-        bail!(span, "the result is not a real number")
+        bail!(error!(span, "the result is not a real number").with_code("E0201"))
     }
 
     Ok(result)
@@ -421,7 +424,7 @@ pub fn ln(
 ) -> SourceResult<f64> {
     let number = value.v.float();
     if number <= 0.0 {
-        bail!(value.span, "value must be strictly positive")
+        bail!(error!(value.span, "value must be strictly positive").with_code("E0201"))
     }
 
     let result = number.ln();
@@ -806,7 +809,7 @@ pub fn rem(
     divisor: Spanned<Num>,
 ) -> SourceResult<Num> {
     if divisor.v.float() == 0.0 {
-        bail!(divisor.span, "divisor must not be zero");
+        bail!(error!(divisor.span, "divisor must not be zero").with_code("E0202"));
     }
     Ok(dividend.apply2(divisor.v, Rem::rem, Rem::rem))
 }
@@ -831,7 +834,7 @@ pub fn div_euclid(
     divisor: Spanned<Num>,
 ) -> SourceResult<Num> {
     if divisor.v.float() == 0.0 {
-        bail!(divisor.span, "divisor must not be zero");
+        bail!(error!(divisor.span, "divisor must not be zero").with_code("E0202"));
     }
     Ok(dividend.apply2(divisor.v, i64::div_euclid, f64::div_euclid))
 }
@@ -857,7 +860,7 @@ pub fn rem_euclid(
     divisor: Spanned<Num>,
 ) -> SourceResult<Num> {
     if divisor.v.float() == 0.0 {
-        bail!(divisor.span, "divisor must not be zero");
+        bail!(error!(divisor.span, "divisor must not be zero").with_code("E0202"));
     }
     Ok(dividend.apply2(divisor.v, i64::rem_euclid, f64::rem_euclid))
 }
@@ -877,12 +880,44 @@ pub fn quo(
     divisor: Spanned<Num>,
 ) -> SourceResult<i64> {
     if divisor.v.float() == 0.0 {
-        bail!(divisor.span, "divisor must not be zero");
+        bail!(error!(divisor.span, "divisor must not be zero").with_code("E0202"));
     }
 
     Ok(floor(dividend.apply2(divisor.v, Div::div, Div::div)))
 }
 
+/// Generates pseudo-random numbers between zero (inclusive) and one
+/// (exclusive).
+///
+/// The numbers only depend on the seed, so the same seed always yields the
+/// same numbers and your document stays reproducible. To get random integers
+/// in a range, scale and [floor]($calc.floor) the result. To shuffle an
+/// array, use its [`shuffled`]($array.shuffled) method.
+///
+/// ```example
+/// #calc.random(42) \
+/// #calc.random(42, count: 3) \
+/// #calc.random(7, count: 5).map(x => calc.floor(x * 6) + 1)
+/// ```
+#[func]
+pub fn random(
+    /// The seed that determines the numbers.
+    seed: i64,
+    /// How many numbers to generate. If given, an array of numbers is
+    /// returned instead of a single number.
+    #[named]
+    count: Option<usize>,
+) -> Value {
+    let mut rng = Rng::new(seed as u64);
+    match count {
+        Some(count) => (0..count)
+            .map(|_| rng.next_f64().into_value())
+            .collect::<Array>()
+            .into_value(),
+        None => rng.next_f64().into_value(),
+    }
+}
+
 /// A value which can be passed to functions that work with integers and floats.
 #[derive(Debug, Copy, Clone)]
 pub enum Num {
@@ -1,3 +1,4 @@
+use ::base64::Engine;
 use typst::diag::{format_xml_like_error, FileError};
 use typst::eval::Bytes;
 use typst::syntax::is_newline;
@@ -13,6 +14,10 @@ pub(super) fn define(global: &mut Scope) {
     global.define_func::<toml>();
     global.define_func::<yaml>();
     global.define_func::<cbor>();
+    global.define_func::<base64>();
+    global.define_func::<hex>();
+    global.define_func::<percent_encode>();
+    global.define_func::<percent_decode>();
     global.define_func::<xml>();
 }
 
@@ -500,6 +505,204 @@ impl cbor {
     }
 }
 
+/// Reads binary data from a Base64-encoded file.
+///
+/// Whitespace in the file, such as line breaks, is ignored. With the
+/// [`encode`]($base64.encode) function, you can also turn binary data into
+/// Base64, for example to construct a data URL.
+///
+/// ```example
+/// #let data = base64.decode("SGVsbG8h")
+/// #str(data) \
+/// #base64.encode("Hello!")
+/// ```
+#[func(scope, title = "Base64")]
+pub fn base64(
+    /// The virtual machine.
+    vm: &mut Vm,
+    /// Path to a Base64 file.
+    path: Spanned<EcoString>,
+) -> SourceResult<Bytes> {
+    let Spanned { v: path, span } = path;
+    let id = vm.resolve_path(&path).at(span)?;
+    let data = vm.world().file(id).at(span)?;
+    base64::decode(Spanned::new(Readable::Bytes(data), span))
+}
+
+#[scope]
+impl base64 {
+    /// Decodes binary data from a Base64 string/bytes.
+    #[func(title = "Decode Base64")]
+    pub fn decode(
+        /// Base64 data.
+        data: Spanned<Readable>,
+    ) -> SourceResult<Bytes> {
+        let Spanned { v: data, span } = data;
+        let trimmed: Vec<u8> = data
+            .as_slice()
+            .iter()
+            .copied()
+            .filter(|b| !b.is_ascii_whitespace())
+            .collect();
+        ::base64::engine::general_purpose::STANDARD
+            .decode(trimmed)
+            .map(Bytes::from)
+            .map_err(|err| eco_format!("failed to decode Base64 ({err})"))
+            .at(span)
+    }
+
+    /// Encodes a string or bytes into a Base64 string.
+    #[func(title = "Encode Base64")]
+    pub fn encode(
+        /// Data to be encoded.
+        data: Readable,
+    ) -> Str {
+        ::base64::engine::general_purpose::STANDARD
+            .encode(data.as_slice())
+            .into()
+    }
+}
+
+/// Reads binary data from a hex-encoded file.
+///
+/// Whitespace in the file, such as line breaks, is ignored. With the
+/// [`encode`]($hex.encode) function, you can also turn binary data into
+/// lowercase hexadecimal digits.
+///
+/// ```example
+/// #let data = hex.decode("48656c6c6f21")
+/// #str(data) \
+/// #hex.encode("Hello!")
+/// ```
+#[func(scope, title = "Hex")]
+pub fn hex(
+    /// The virtual machine.
+    vm: &mut Vm,
+    /// Path to a hex file.
+    path: Spanned<EcoString>,
+) -> SourceResult<Bytes> {
+    let Spanned { v: path, span } = path;
+    let id = vm.resolve_path(&path).at(span)?;
+    let data = vm.world().file(id).at(span)?;
+    hex::decode(Spanned::new(Readable::Bytes(data), span))
+}
+
+#[scope]
+impl hex {
+    /// Decodes binary data from a hex string/bytes.
+    #[func(title = "Decode Hex")]
+    pub fn decode(
+        /// Hex data.
+        data: Spanned<Readable>,
+    ) -> SourceResult<Bytes> {
+        let Spanned { v: data, span } = data;
+        let digits: Vec<u8> = data
+            .as_slice()
+            .iter()
+            .copied()
+            .filter(|b| !b.is_ascii_whitespace())
+            .collect();
+        if digits.len() % 2 != 0 {
+            bail!(span, "failed to decode hex (odd number of digits)");
+        }
+
+        digits
+            .chunks(2)
+            .map(|pair| {
+                hex_byte(pair).ok_or_else(|| {
+                    let pair = String::from_utf8_lossy(pair);
+                    eco_format!("failed to decode hex (invalid digits `{pair}`)")
+                })
+            })
+            .collect::<StrResult<Vec<u8>>>()
+            .map(Bytes::from)
+            .at(span)
+    }
+
+    /// Encodes a string or bytes into a string of lowercase hex digits.
+    #[func(title = "Encode Hex")]
+    pub fn encode(
+        /// Data to be encoded.
+        data: Readable,
+    ) -> Str {
+        let mut hex = EcoString::new();
+        for byte in data.as_slice() {
+            hex.push_str(&eco_format!("{byte:02x}"));
+        }
+        hex.into()
+    }
+}
+
+/// Encodes a string for use in a URL by percent-encoding it.
+///
+/// All characters except for ASCII letters, digits, and `-`, `.`, `_`, and
+/// `~` are replaced by a percent sign followed by the hex digits of their
+/// UTF-8 bytes. This makes the result safe to use as a URL component, for
+/// example in a query string.
+///
+/// ```example
+/// #let query = percent-encode("typst & friends")
+/// #link("https://example.com/?q=" + query)
+/// ```
+#[func(title = "Percent-Encode")]
+pub fn percent_encode(
+    /// The text to encode.
+    text: Str,
+) -> Str {
+    let mut encoded = EcoString::new();
+    for &byte in text.as_bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&eco_format!("%{byte:02X}"));
+        }
+    }
+    encoded.into()
+}
+
+/// Decodes a percent-encoded string, for example a component of a URL.
+///
+/// ```example
+/// #percent-decode("typst%20%26%20friends")
+/// ```
+#[func(title = "Percent-Decode")]
+pub fn percent_decode(
+    /// The text to decode.
+    text: Spanned<Str>,
+) -> SourceResult<Str> {
+    let Spanned { v: text, span } = text;
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte != b'%' {
+            bytes.push(byte);
+            rest = tail;
+            continue;
+        }
+
+        let Some(decoded) = tail.get(..2).and_then(hex_byte) else {
+            bail!(span, "failed to decode percent-encoding (invalid escape sequence)");
+        };
+
+        bytes.push(decoded);
+        rest = &tail[2..];
+    }
+
+    String::from_utf8(bytes)
+        .map(Into::into)
+        .map_err(|_| "failed to decode percent-encoding (invalid UTF-8)")
+        .at(span)
+}
+
+/// Decodes a byte from two hex digits.
+fn hex_byte(digits: &[u8]) -> Option<u8> {
+    let digit = |d: u8| (d as char).to_digit(16);
+    match digits {
+        &[hi, lo] => Some((digit(hi)? * 16 + digit(lo)?) as u8),
+        _ => None,
+    }
+}
+
 /// Reads structured data from an XML file.
 ///
 /// The XML file is parsed into an array of dictionaries and strings. XML nodes
@@ -1,5 +1,6 @@
 use typst::eval::{
-    Datetime, Duration, EvalMode, Module, Never, NoneValue, Plugin, Regex, Repr, Version,
+    Datetime, Decimal, Duration, EvalMode, Module, Never, NoneValue, Plugin, Regex, Repr,
+    Version,
 };
 
 use crate::prelude::*;
@@ -10,6 +11,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define_type::<bool>();
     global.define_type::<i64>();
     global.define_type::<f64>();
+    global.define_type::<Decimal>();
     global.define_type::<Str>();
     global.define_type::<Bytes>();
     global.define_type::<Content>();
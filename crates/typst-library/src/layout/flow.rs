@@ -1,6 +1,7 @@
 use std::mem;
 
 use comemo::Prehashed;
+use typst::diag::warning;
 
 use crate::layout::{
     AlignElem, BlockElem, ColbreakElem, ColumnsElem, ParElem, PlaceElem, Spacing, VElem,
@@ -61,7 +62,7 @@ impl Layout for FlowElem {
                 || child.is::<PathElem>()
             {
                 let layoutable = child.with::<dyn Layout>().unwrap();
-                layouter.layout_single(vt, layoutable, styles)?;
+                layouter.layout_single(vt, layoutable, styles, child.span())?;
             } else if child.is::<MetaElem>() {
                 let mut frame = Frame::soft(Size::zero());
                 frame.meta(styles, true);
@@ -245,6 +246,8 @@ impl<'a> FlowLayouter<'a> {
                 self.layout_item(vt, FlowItem::Absolute(leading, true))?;
             }
 
+            self.warn_overflow(vt, &frame, par.span());
+
             self.layout_item(
                 vt,
                 FlowItem::Frame { frame, align, sticky: false, movable: true },
@@ -262,16 +265,45 @@ impl<'a> FlowLayouter<'a> {
         vt: &mut Vt,
         content: &dyn Layout,
         styles: StyleChain,
+        span: Span,
     ) -> SourceResult<()> {
         let align = AlignElem::alignment_in(styles).resolve(styles);
         let sticky = BlockElem::sticky_in(styles);
         let pod = Regions::one(self.regions.base(), Axes::splat(false));
         let frame = content.layout(vt, styles, pod)?.into_frame();
+        self.warn_overflow(vt, &frame, span);
         self.layout_item(vt, FlowItem::Frame { frame, align, sticky, movable: true })?;
         self.last_was_par = false;
         Ok(())
     }
 
+    /// Warn if a frame in the root flow doesn't fit onto a page. Frames can't
+    /// be broken any further, so such content sticks out of the page.
+    fn warn_overflow(&self, vt: &mut Vt, frame: &Frame, span: Span) {
+        if !self.root {
+            return;
+        }
+
+        let full = self.regions.base();
+        if !full.x.fits(frame.width()) {
+            let overflow = frame.width() - full.x;
+            vt.tracer.warn(warning!(
+                span,
+                "content overflows the page horizontally by {}",
+                overflow.repr()
+            ));
+        }
+
+        if !full.y.fits(frame.height()) {
+            let overflow = frame.height() - full.y;
+            vt.tracer.warn(warning!(
+                span,
+                "content overflows the page vertically by {}",
+                overflow.repr()
+            ));
+        }
+    }
+
     /// Layout a placed element.
     fn layout_placed(
         &mut self,
@@ -337,6 +369,7 @@ impl<'a> FlowLayouter<'a> {
                 self.finish_region(vt)?;
             }
 
+            self.warn_overflow(vt, &frame, block.span());
             let item = FlowItem::Frame { frame, align, sticky, movable: false };
             self.layout_item(vt, item)?;
         }
@@ -706,6 +739,9 @@ fn find_footnotes(notes: &mut Vec<FootnoteElem>, frame: &Frame) {
                 if !notes.iter().any(|note| note.location() == content.location()) =>
             {
                 let Some(footnote) = content.to::<FootnoteElem>() else { continue };
+                if footnote.endnote(StyleChain::default()) {
+                    continue;
+                }
                 notes.push(footnote.clone());
             }
             _ => {}
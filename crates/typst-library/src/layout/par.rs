@@ -1,4 +1,5 @@
 use comemo::Prehashed;
+use typst::diag::warning;
 use typst::eval::Tracer;
 use typst::model::DelayedErrors;
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
@@ -729,6 +730,12 @@ fn prepare<'a>(
         cursor = end;
     }
 
+    for item in &items {
+        if let Item::Text(shaped) = item {
+            warn_missing_glyphs(vt, shaped);
+        }
+    }
+
     let cjk_latin_spacing = TextElem::cjk_latin_spacing_in(styles).is_auto();
     if cjk_latin_spacing {
         add_cjk_latin_spacing(&mut items);
@@ -751,6 +758,54 @@ fn prepare<'a>(
     })
 }
 
+/// Warn about characters for which no font had a glyph and that are thus
+/// shown as tofus. All such characters of a text run are reported together.
+fn warn_missing_glyphs(vt: &mut Vt, shaped: &ShapedText) {
+    let mut missing = vec![];
+    let mut span = Span::detached();
+    for glyph in shaped.glyphs.iter() {
+        if glyph.glyph_id != 0 || glyph.c.is_whitespace() || glyph.c.is_control() {
+            continue;
+        }
+
+        if missing.is_empty() {
+            span = glyph.span.0;
+        }
+
+        if !missing.contains(&glyph.c) {
+            missing.push(glyph.c);
+        }
+    }
+
+    if missing.is_empty() {
+        return;
+    }
+
+    // The families are stored lowercased, so we look up their proper names.
+    let book = vt.world.book();
+    let tried: Vec<EcoString> = TextElem::font_in(shaped.styles)
+        .into_iter()
+        .map(|family| {
+            book.select_family(family.as_str())
+                .next()
+                .and_then(|id| book.info(id))
+                .map_or(family.as_str(), |info| info.family.as_str())
+                .into()
+        })
+        .collect();
+    let mut hint = eco_format!("tried font families: {}", tried.join(", "));
+    if TextElem::fallback_in(shaped.styles) {
+        hint.push_str(" and fallback fonts");
+    }
+
+    let chars: Vec<_> = missing.iter().map(|c| eco_format!("`{c}`")).collect();
+    let noun = if missing.len() == 1 { "character" } else { "characters" };
+    vt.tracer.warn(
+        warning!(span, "no font contains the {noun} {}", chars.join(", "))
+            .with_hint(hint),
+    );
+}
+
 /// Add some spacing between Han characters and western characters.
 /// See Requirements for Chinese Text Layout, Section 3.2.2 Mixed Text Composition in Horizontal
 /// Written Mode
@@ -1146,6 +1201,11 @@ fn line<'a>(
         dash = hyphen || shy || trimmed.ends_with(['-', '–', '—']);
         justify |= text.ends_with('\u{2028}');
 
+        // Mark lines that were broken within continued text.
+        let continued = breakpoint != Breakpoint::Mandatory
+            && end < p.bidi.text.len()
+            && TextElem::continuation_in(shaped.styles);
+
         // Deal with CJK punctuation at line ends.
         let gb_style = is_gb_style(shaped.lang, shaped.region);
         let maybe_adjust_last_glyph = trimmed.ends_with(END_PUNCT_PAT)
@@ -1160,13 +1220,21 @@ fn line<'a>(
         // need the shaped empty string to make the line the appropriate
         // height. That is the case exactly if the string is empty and there
         // are no other items in the line.
-        if hyphen || start + shaped.text.len() > range.end || maybe_adjust_last_glyph {
-            if hyphen || start < range.end || before.is_empty() {
+        if hyphen
+            || continued
+            || start + shaped.text.len() > range.end
+            || maybe_adjust_last_glyph
+        {
+            if hyphen || continued || start < range.end || before.is_empty() {
                 let mut reshaped = shaped.reshape(vt, &p.spans, start..range.end);
                 if hyphen || shy {
                     reshaped.push_hyphen(vt, p.fallback);
                 }
 
+                if continued {
+                    reshaped.push_continuation(vt, p.fallback);
+                }
+
                 if let Some(last_glyph) = reshaped.glyphs.last() {
                     if last_glyph.is_cjk_left_aligned_punctuation(gb_style) {
                         // If the last glyph is a CJK punctuation, we want to shrink it.
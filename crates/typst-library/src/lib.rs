@@ -94,6 +94,7 @@ fn items() -> LangItems {
         layout: |world, content, styles| content.layout_root(world, styles),
         em: text::TextElem::size_in,
         dir: text::TextElem::dir_in,
+        lang: text::TextElem::lang_in,
         space: || text::SpaceElem::new().pack(),
         linebreak: || text::LinebreakElem::new().pack(),
         text: |text| text::TextElem::new(text).pack(),
@@ -232,6 +232,21 @@ impl Counter {
         Ok(CounterState(smallvec![at_state.first(), final_state.first()]))
     }
 
+    /// Gets the value of the counter at the end of the given page.
+    pub fn at_page(&self, vt: &mut Vt, page: NonZeroUsize) -> SourceResult<CounterState> {
+        let sequence = self.sequence(vt)?;
+        let (mut state, last) = sequence
+            .iter()
+            .take_while(|(_, stop)| *stop <= page)
+            .last()
+            .unwrap()
+            .clone();
+        if self.is_page() {
+            state.step(NonZeroUsize::ONE, page.get().saturating_sub(last.get()));
+        }
+        Ok(state)
+    }
+
     /// Produce the whole sequence of counter states.
     ///
     /// This has to happen just once for all counters, cutting down the number
@@ -1,7 +1,7 @@
 use comemo::Prehashed;
 use std::str::FromStr;
 
-use crate::layout::{HElem, ParElem};
+use crate::layout::{HElem, ParElem, VElem};
 use crate::meta::{Count, Counter, CounterUpdate, Numbering, NumberingPattern};
 use crate::prelude::*;
 use crate::text::{SuperElem, TextElem, TextSize};
@@ -62,6 +62,22 @@ pub struct FootnoteElem {
     #[default(Numbering::Pattern(NumberingPattern::from_str("1").unwrap()))]
     pub numbering: Numbering,
 
+    /// Whether to collect the note in the next
+    /// [endnote listing]($footnote.endnotes) instead of putting it at the
+    /// bottom of the page.
+    ///
+    /// ```example
+    /// #set footnote(endnote: true)
+    ///
+    /// Endnotes #footnote[First]
+    /// are listed later.
+    /// #footnote[Second]
+    ///
+    /// #footnote.endnotes()
+    /// ```
+    #[default(false)]
+    pub endnote: bool,
+
     /// The content to put into the footnote. Can also be the label of another
     /// footnote this one should point to.
     #[required]
@@ -72,6 +88,9 @@ pub struct FootnoteElem {
 impl FootnoteElem {
     #[elem]
     type FootnoteEntry;
+
+    #[elem]
+    type EndnotesElem;
 }
 
 impl FootnoteElem {
@@ -116,6 +135,7 @@ impl FootnoteElem {
 impl Synthesize for FootnoteElem {
     fn synthesize(&mut self, _vt: &mut Vt, styles: StyleChain) -> SourceResult<()> {
         self.push_numbering(self.numbering(styles).clone());
+        self.push_endnote(self.endnote(styles));
         Ok(())
     }
 }
@@ -303,3 +323,89 @@ cast! {
     FootnoteElem,
     v: Content => v.to::<Self>().cloned().unwrap_or_else(|| Self::with_content(v.clone())),
 }
+
+/// A listing of endnotes.
+///
+/// Shows the entries of all footnotes with [`endnote`]($footnote.endnote) set
+/// to `{true}` since the previous listing or the start of the document. This
+/// way, notes can be collected at the end of each chapter or of the whole
+/// document. The entries are styled just like those at the bottom of the page,
+/// so [`footnote.entry`]($footnote.entry) applies to them, too. Each entry is
+/// followed by a back-reference to every place that refers to the note.
+///
+/// ```example
+/// #set footnote(endnote: true)
+///
+/// = Introduction
+/// Some claim.#footnote[A source.]
+///
+/// #footnote.endnotes()
+///
+/// = Conclusion
+/// Another claim.#footnote[Another source.]
+///
+/// #footnote.endnotes()
+/// ```
+#[elem(name = "endnotes", title = "Endnotes", Locatable, Show)]
+pub struct EndnotesElem {}
+
+impl Show for EndnotesElem {
+    #[tracing::instrument(name = "EndnotesElem::show", skip_all)]
+    fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
+        Ok(vt.delayed(|vt| {
+            let location = self.location().unwrap();
+            let mut selector =
+                Selector::Elem(FootnoteElem::elem(), None).before(location.into(), false);
+
+            // Only collect the notes since the previous listing.
+            let previous =
+                Selector::Elem(Self::elem(), None).before(location.into(), false);
+            if let Some(previous) = vt.introspector.query(&previous).last() {
+                selector = selector.after(previous.location().unwrap().into(), false);
+            }
+
+            let gap = FootnoteEntry::gap_in(styles);
+            let mut seq = vec![];
+            for note in vt.introspector.query(&selector) {
+                let note = note.to::<FootnoteElem>().unwrap();
+                if note.is_ref() || !note.endnote(StyleChain::default()) {
+                    continue;
+                }
+
+                if !seq.is_empty() {
+                    seq.push(VElem::weak(gap.into()).pack());
+                }
+                seq.push(FootnoteEntry::new(note.clone()).pack());
+                seq.extend(backrefs(vt, note));
+            }
+
+            Ok(Content::sequence(seq))
+        }))
+    }
+}
+
+/// Links back to the footnote's marker and to those of all footnotes
+/// referencing it by label.
+fn backrefs(vt: &Vt, note: &FootnoteElem) -> Vec<Content> {
+    let mut markers: Vec<Location> = note.location().into_iter().collect();
+    if let Some(label) = note.label() {
+        let selector = Selector::Elem(FootnoteElem::elem(), None);
+        markers.extend(vt.introspector.query(&selector).iter().filter_map(|elem| {
+            let other = elem.to::<FootnoteElem>()?;
+            match other.body() {
+                FootnoteBody::Reference(target) if *target == label => other.location(),
+                _ => None,
+            }
+        }));
+    }
+
+    markers
+        .into_iter()
+        .flat_map(|loc| {
+            [
+                HElem::new(Em::new(0.25).into()).with_weak(true).pack(),
+                TextElem::packed("↩").linked(Destination::Location(loc)),
+            ]
+        })
+        .collect()
+}
@@ -38,6 +38,21 @@ use crate::text::{SpaceElem, TextElem, TextSize};
 /// Headings have dedicated syntax: They can be created by starting a line with
 /// one or multiple equals signs, followed by a space. The number of equals
 /// signs determines the heading's logical nesting depth.
+///
+/// # Styling per level
+/// There is no limit on how deeply headings can be nested. By default, the
+/// first two levels are set larger than the rest. To style the headings of a
+/// specific level differently, use a show-set or show rule with a `where`
+/// selector on the `level` field:
+///
+/// ```example
+/// #show heading.where(level: 1): set text(fill: navy)
+/// #show heading.where(level: 3): it => emph(it.body)
+///
+/// = Chapter
+/// == Section
+/// === Paragraph
+/// ```
 #[elem(Locatable, Synthesize, Count, Show, Finalize, LocalName, Refable, Outlinable)]
 pub struct HeadingElem {
     /// The logical nesting depth of the heading, starting from one.
@@ -1,3 +1,4 @@
+use super::query_label;
 use crate::prelude::*;
 use crate::text::{Hyphenate, TextElem};
 
@@ -89,7 +90,7 @@ impl Show for LinkElem {
             LinkTarget::Dest(dest) => body.linked(dest.clone()),
             LinkTarget::Label(label) => vt
                 .delayed(|vt| {
-                    let elem = vt.introspector.query_label(*label).at(self.span())?;
+                    let elem = query_label(vt, *label, self.span())?;
                     let dest = Destination::Location(elem.location().unwrap());
                     Ok(Some(body.clone().linked(dest)))
                 })
@@ -34,6 +34,10 @@ pub use self::query_::*;
 pub use self::reference::*;
 pub use self::state::*;
 
+use comemo::Prehashed;
+use ecow::eco_vec;
+use typst::diag::SourceDiagnostic;
+
 use crate::prelude::*;
 use crate::text::TextElem;
 
@@ -74,3 +78,15 @@ pub trait LocalNameIn: LocalName {
 }
 
 impl<T: LocalName> LocalNameIn for T {}
+
+/// Find the unique element with the given label. If the label occurs multiple
+/// times, the error points to all of its occurrences.
+fn query_label(vt: &Vt, label: Label, span: Span) -> SourceResult<Prehashed<Content>> {
+    vt.introspector.query_label(label).map_err(|message| {
+        let mut error = SourceDiagnostic::error(span, message);
+        for elem in vt.introspector.query(&Selector::Label(label)) {
+            error = error.with_related(elem.span(), "label is attached here");
+        }
+        eco_vec![error]
+    })
+}
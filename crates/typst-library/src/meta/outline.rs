@@ -1,10 +1,11 @@
 use std::str::FromStr;
 
+use typst::eval::locale;
 use typst::util::option_eq;
 
 use crate::layout::{BoxElem, HElem, HideElem, ParbreakElem, RepeatElem, Spacing};
 use crate::meta::{
-    Counter, CounterKey, HeadingElem, LocalName, LocalNameIn, Numbering,
+    Counter, CounterKey, CounterState, HeadingElem, LocalName, LocalNameIn, Numbering,
     NumberingPattern, Refable,
 };
 use crate::prelude::*;
@@ -173,6 +174,28 @@ pub struct OutlineElem {
     /// ```
     #[default(Some(RepeatElem::new(TextElem::packed(".")).pack()))]
     pub fill: Option<Content>,
+
+    /// Whether to show the range of pages an entry's section spans instead of
+    /// just the page it starts on.
+    ///
+    /// A section ends on the page before the next element of the same or a
+    /// higher level starts, or on the last page of the document. This works
+    /// best for parts and chapters that start on a new page.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #outline(page-range: true)
+    /// #pagebreak()
+    ///
+    /// = Beginnings
+    /// #pagebreak()
+    /// More beginnings.
+    /// #pagebreak()
+    ///
+    /// = Endings
+    /// ```
+    #[default(false)]
+    pub page_range: bool,
 }
 
 #[scope]
@@ -196,16 +219,36 @@ impl Show for OutlineElem {
 
         let indent = self.indent(styles);
         let depth = self.depth(styles).unwrap_or(NonZeroUsize::new(usize::MAX).unwrap());
+        let page_range = self.page_range(styles);
 
         let mut ancestors: Vec<&Content> = vec![];
         let elems = vt.introspector.query(&self.target(styles).0);
 
-        for elem in &elems {
+        for (i, elem) in elems.iter().enumerate() {
+            // The section of an element ends where the next element of the same
+            // or a higher level starts.
+            let end = if page_range {
+                let level = elem.with::<dyn Outlinable>().map(|o| o.level());
+                Some(
+                    elems[i + 1..]
+                        .iter()
+                        .find(|next| {
+                            next.with::<dyn Outlinable>()
+                                .map_or(false, |next| Some(next.level()) <= level)
+                        })
+                        .and_then(|next| next.location()),
+                )
+            } else {
+                None
+            };
+
             let Some(entry) = OutlineEntry::from_outlinable(
                 vt,
                 self.span(),
                 elem.clone().into_inner(),
                 self.fill(styles),
+                end,
+                TextElem::lang_in(styles),
             )?
             else {
                 continue;
@@ -440,7 +483,9 @@ pub struct OutlineEntry {
     pub fill: Option<Content>,
 
     /// The page number of the element this entry links to, formatted with the
-    /// numbering set for the referenced page.
+    /// numbering set for the referenced page. If the outline shows
+    /// [page ranges]($outline.page-range), this is the range of pages the
+    /// element's section spans, like "12–15".
     #[required]
     pub page: Content,
 }
@@ -450,11 +495,17 @@ impl OutlineEntry {
     /// the element does not implement `Outlinable`). If the element should not
     /// be outlined (e.g. heading with 'outlined: false'), does not generate an
     /// entry instance (returns `Ok(None)`).
+    ///
+    /// If `end` is given, the entry shows the range of pages up to the start
+    /// of the next section (or the end of the document if there is none).
+    /// Arabic page numbers are grouped like numbers in the given language.
     fn from_outlinable(
         vt: &mut Vt,
         span: Span,
         elem: Content,
         fill: Option<Content>,
+        end: Option<Option<Location>>,
+        lang: Lang,
     ) -> SourceResult<Option<Self>> {
         let Some(outlinable) = elem.with::<dyn Outlinable>() else {
             bail!(span, "cannot outline {}", elem.func().name());
@@ -465,23 +516,57 @@ impl OutlineEntry {
         };
 
         let location = elem.location().unwrap();
-        let page_numbering = vt
-            .introspector
-            .page_numbering(location)
-            .cast::<Option<Numbering>>()
-            .unwrap()
-            .unwrap_or_else(|| {
-                Numbering::Pattern(NumberingPattern::from_str("1").unwrap())
-            });
+        let first = vt.introspector.page(location);
+        let start = Counter::new(CounterKey::Page).at(vt, location)?;
+        let mut page = display_page(vt, start, &page_numbering(vt, first), lang)?;
+
+        if let Some(end) = end {
+            let last = match end {
+                Some(next) => vt.introspector.page(next).get().saturating_sub(1),
+                None => vt.introspector.pages().get(),
+            };
 
-        let page = Counter::new(CounterKey::Page)
-            .at(vt, location)?
-            .display(vt, &page_numbering)?;
+            if let Some(last) = NonZeroUsize::new(last).filter(|&last| last > first) {
+                let state = Counter::new(CounterKey::Page).at_page(vt, last)?;
+                page += TextElem::packed('\u{2013}');
+                page += display_page(vt, state, &page_numbering(vt, last), lang)?;
+            }
+        }
 
         Ok(Some(Self::new(outlinable.level(), elem, body, fill, page)))
     }
 }
 
+/// The numbering of the given page, defaulting to arabic numerals.
+fn page_numbering(vt: &Vt, page: NonZeroUsize) -> Numbering {
+    vt.introspector
+        .page_numbering_of(page)
+        .cast::<Option<Numbering>>()
+        .unwrap()
+        .unwrap_or_else(arabic)
+}
+
+/// Plain arabic numerals.
+fn arabic() -> Numbering {
+    Numbering::Pattern(NumberingPattern::from_str("1").unwrap())
+}
+
+/// Display a page number with its numbering, grouping the digits of plain
+/// arabic numbers like the language does.
+fn display_page(
+    vt: &mut Vt,
+    state: CounterState,
+    numbering: &Numbering,
+    lang: Lang,
+) -> SourceResult<Content> {
+    match state.0.as_slice() {
+        [number] if *numbering == arabic() => {
+            Ok(TextElem::packed(locale::format_number(&number.to_string(), lang)))
+        }
+        _ => state.display(vt, numbering),
+    }
+}
+
 impl Show for OutlineEntry {
     fn show(&self, _vt: &mut Vt, _: StyleChain) -> SourceResult<Content> {
         let mut seq = vec![];
@@ -1,4 +1,4 @@
-use super::{BibliographyElem, CiteElem, Counter, Figurable, Numbering};
+use super::{query_label, BibliographyElem, CiteElem, Counter, Figurable, Numbering};
 use crate::math::EquationElem;
 use crate::meta::FootnoteElem;
 use crate::prelude::*;
@@ -150,8 +150,8 @@ impl Show for RefElem {
     fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
         Ok(vt.delayed(|vt| {
             let target = *self.target();
-            let elem = vt.introspector.query_label(target);
             let span = self.span();
+            let elem = query_label(vt, target, span);
 
             if BibliographyElem::has(vt, target) {
                 if elem.is_ok() {
@@ -161,7 +161,7 @@ impl Show for RefElem {
                 return Ok(self.to_citation(vt, styles)?.spanned(span).pack());
             }
 
-            let elem = elem.at(span)?;
+            let elem = elem?;
 
             if elem.func() == FootnoteElem::elem() {
                 return Ok(FootnoteElem::with_label(target).spanned(span).pack());
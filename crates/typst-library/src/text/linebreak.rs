@@ -1,3 +1,4 @@
+use ecow::EcoString;
 use icu_properties::{maps::CodePointMapData, LineBreak};
 use icu_provider::AsDeserializingBufferProvider;
 use icu_provider_adapters::fork::ForkByKeyProvider;
@@ -83,6 +84,13 @@ pub(crate) fn breakpoints<'a>(
     p: &'a Preparation<'a>,
     mut f: impl FnMut(usize, Breakpoint),
 ) {
+    // Filter out opportunities within content that must not be broken.
+    let mut f = |end, breakpoint| {
+        if breakpoint == Breakpoint::Mandatory || !nobreak_at(p, end) {
+            f(end, breakpoint);
+        }
+    };
+
     let text = p.bidi.text;
     let hyphenate = p.hyphenate != Some(false);
     let lb = LINEBREAK_DATA.as_borrowed();
@@ -145,10 +153,25 @@ pub(crate) fn breakpoints<'a>(
             let end = last + word.len();
             let mut offset = last;
 
-            // Determine the language to hyphenate this word in.
-            let Some(lang) = lang_at(p, last) else { break 'hyphenate };
+            // Split the word into syllables, preferring user-defined
+            // exceptions over the language's hyphenation patterns.
+            let mut syllables = vec![];
+            if let Some(exception) = exception_at(p, last, word) {
+                let mut start = 0;
+                for part in exception.split('-') {
+                    let Some(syllable) = word.get(start..start + part.len()) else {
+                        break 'hyphenate;
+                    };
+                    syllables.push(syllable);
+                    start += part.len();
+                }
+            } else {
+                // Determine the language to hyphenate this word in.
+                let Some(lang) = lang_at(p, last) else { break 'hyphenate };
+                syllables.extend(hypher::hyphenate(word, lang));
+            }
 
-            for syllable in hypher::hyphenate(word, lang) {
+            for syllable in syllables {
                 // Don't hyphenate after the final syllable.
                 offset += syllable.len();
                 if offset == end {
@@ -249,6 +272,27 @@ fn hyphenate_at(p: &Preparation, offset: usize) -> bool {
         .unwrap_or(false)
 }
 
+/// Whether a line break at the given offset is forbidden because the text on
+/// both sides of it must not be broken.
+fn nobreak_at(p: &Preparation, offset: usize) -> bool {
+    let nobreak = |offset| {
+        p.find(offset)
+            .and_then(|item| item.text())
+            .map_or(false, |shaped| TextElem::nobreak_in(shaped.styles))
+    };
+    offset > 0 && nobreak(offset - 1) && nobreak(offset)
+}
+
+/// The user-defined hyphenation of the word at the given offset, if any.
+fn exception_at(p: &Preparation, offset: usize, word: &str) -> Option<EcoString> {
+    let shaped = p.find(offset)?.text()?;
+    TextElem::hyphenation_in(shaped.styles).into_iter().find(|exception| {
+        let unhyphenated = exception.replace('-', "");
+        unhyphenated.len() == word.len()
+            && unhyphenated.to_lowercase() == word.to_lowercase()
+    })
+}
+
 /// The text language at the given offset.
 fn lang_at(p: &Preparation, offset: usize) -> Option<hypher::Lang> {
     let lang = p.lang.or_else(|| {
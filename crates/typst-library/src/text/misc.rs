@@ -291,6 +291,29 @@ pub fn smallcaps(
     body.styled(TextElem::set_smallcaps(true))
 }
 
+/// Keeps content together on one line.
+///
+/// Line breaks are never inserted within the content, not even at spaces or
+/// hyphenation points. Unlike a [box]($box), the content still takes part in
+/// justification. This is useful for short phrases like "Figure 3" or version
+/// numbers that should not be torn apart. Explicit line breaks are still
+/// honored.
+///
+/// # Example
+/// ```example
+/// #set page(width: 120pt)
+/// The results are shown in
+/// #nobreak[Figure 3] and discussed
+/// in detail afterwards.
+/// ```
+#[func(title = "No Break")]
+pub fn nobreak(
+    /// The content to keep together.
+    body: Content,
+) -> Content {
+    body.styled(TextElem::set_nobreak(true))
+}
+
 /// Creates blind text.
 ///
 /// This function yields a Latin-like _Lorem Ipsum_ blind text with the given
@@ -46,6 +46,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define_func::<lower>();
     global.define_func::<upper>();
     global.define_func::<smallcaps>();
+    global.define_func::<nobreak>();
     global.define_func::<lorem>();
 }
 
@@ -434,6 +435,28 @@ pub struct TextElem {
     #[ghost]
     pub hyphenate: Hyphenate,
 
+    /// Words with custom hyphenation points that take precedence over the
+    /// language's hyphenation patterns.
+    ///
+    /// Each word lists its allowed hyphenation points as hyphens. A word
+    /// without any hyphen is never hyphenated. Matching ignores case. Words
+    /// from nested set rules are added to the ones that are already set.
+    ///
+    /// ```example
+    /// #set page(width: 90pt)
+    /// #set par(justify: true)
+    /// #set text(hyphenate: true)
+    /// Please check our database
+    /// of FORTRAN libraries.
+    ///
+    /// #set text(hyphenation: ("data-base", "FORTRAN"))
+    /// Please check our database
+    /// of FORTRAN libraries.
+    /// ```
+    #[fold]
+    #[ghost]
+    pub hyphenation: Vec<EcoString>,
+
     /// Whether to apply kerning.
     ///
     /// When enabled, specific letter pairings move closer together or further
@@ -622,6 +645,19 @@ pub struct TextElem {
     #[default(false)]
     #[ghost]
     pub smallcaps: bool,
+
+    /// Whether lines may not be broken within the text.
+    #[internal]
+    #[default(false)]
+    #[ghost]
+    pub nobreak: bool,
+
+    /// Whether lines that are broken within the text are marked with a
+    /// continuation marker at their end.
+    #[internal]
+    #[default(false)]
+    #[ghost]
+    pub continuation: bool,
 }
 
 impl TextElem {
@@ -17,7 +17,8 @@ use crate::layout::BlockElem;
 use crate::meta::Figurable;
 use crate::prelude::*;
 use crate::text::{
-    FontFamily, FontList, Hyphenate, LinebreakElem, SmartquoteElem, TextElem, TextSize,
+    FontFamily, FontList, HighlightElem, Hyphenate, LinebreakElem, SmartquoteElem,
+    TextElem, TextSize,
 };
 
 // Shorthand for highlighter closures.
@@ -250,6 +251,40 @@ pub struct RawElem {
     #[default(2)]
     pub tab_size: usize,
 
+    /// How to deal with lines that are too long for the available width.
+    ///
+    /// By default, long lines wrap softly. In raw blocks, each line that is
+    /// broken is marked with a continuation marker (`↩`) at its end.
+    ///
+    /// ````example
+    /// #set raw(wrap: "clip")
+    ///
+    /// ```rust
+    /// let result = compute(first_argument, second_argument);
+    /// ```
+    /// ````
+    #[default(RawWrap::Soft)]
+    pub wrap: RawWrap,
+
+    /// Whether to highlight the changed words within diffs.
+    ///
+    /// With the `{"diff"}` or `{"patch"}` language, lines starting with `+` or
+    /// `-` are colored as additions and removals. When this is enabled, the
+    /// parts that actually differ between a removed line and the added line
+    /// replacing it are additionally highlighted with a background.
+    ///
+    /// ````example
+    /// #set raw(word-diff: true)
+    ///
+    /// ```diff
+    ///  let x = 1;
+    /// -let y = x + 2;
+    /// +let y = x * 2;
+    /// ```
+    /// ````
+    #[default(false)]
+    pub word_diff: bool,
+
     /// The stylized lines of raw text.
     ///
     /// Made accessible for the [`raw.line` element]($raw.line).
@@ -312,6 +347,8 @@ impl Synthesize for RawElem {
 
         let theme = theme.as_deref().unwrap_or(&THEME);
         let foreground = theme.settings.foreground.unwrap_or(synt::Color::BLACK);
+        let word_diff =
+            self.word_diff(styles) && matches!(lang.as_deref(), Some("diff" | "patch"));
 
         let mut seq = vec![];
         if matches!(lang.as_deref(), Some("typ" | "typst" | "typc")) {
@@ -348,20 +385,48 @@ impl Synthesize for RawElem {
                         .map(|syntax| (&**extra_syntaxes, syntax))
                 })
         }) {
+            let changes = if word_diff { diff_changes(&lines) } else { vec![] };
             let mut highlighter = syntect::easy::HighlightLines::new(syntax, theme);
-            for (i, line) in lines.into_iter().enumerate() {
+            for (i, line) in lines.iter().enumerate() {
+                let changed = changes.get(i).cloned().flatten();
                 let mut line_content = vec![];
+                let mut offset = 0;
                 for (style, piece) in
                     highlighter.highlight_line(line, syntax_set).into_iter().flatten()
                 {
-                    line_content.push(styled(piece, foreground, style));
+                    let start = offset;
+                    offset += piece.len();
+                    let Some(changed) = &changed else {
+                        line_content.push(styled(piece, foreground, style));
+                        continue;
+                    };
+
+                    // Split the piece at the boundaries of the changed words.
+                    let a = changed.start.clamp(start, offset) - start;
+                    let b = changed.end.clamp(start, offset) - start;
+                    if a > 0 {
+                        line_content.push(styled(&piece[..a], foreground, style));
+                    }
+                    if b > a {
+                        let fill = if line.starts_with('-') {
+                            Color::from_u8(0xFD, 0xB8, 0xC0, 0xFF)
+                        } else {
+                            Color::from_u8(0xAC, 0xF2, 0xBD, 0xFF)
+                        };
+                        let body = styled(&piece[a..b], foreground, style);
+                        line_content
+                            .push(HighlightElem::new(body).with_fill(fill.into()).pack());
+                    }
+                    if b < piece.len() {
+                        line_content.push(styled(&piece[b..], foreground, style));
+                    }
                 }
 
                 seq.push(
                     RawLine::new(
                         i as i64 + 1,
                         count,
-                        EcoString::from(line),
+                        EcoString::from(*line),
                         Content::sequence(line_content),
                     )
                     .spanned(self.span()),
@@ -397,17 +462,82 @@ impl Show for RawElem {
             lines.push(line.clone().pack());
         }
 
+        let wrap = self.wrap(styles);
         let mut realized = Content::sequence(lines);
+        if wrap != RawWrap::Soft {
+            realized = realized.styled(TextElem::set_nobreak(true));
+        }
+
         if self.block(styles) {
             // Align the text before inserting it into the block.
             realized = realized.aligned(self.align(styles).into());
-            realized = BlockElem::new().with_body(Some(realized)).pack();
+            match wrap {
+                RawWrap::Soft => {
+                    realized = realized.styled(TextElem::set_continuation(true))
+                }
+                RawWrap::Clip => {}
+                RawWrap::Shrink => realized = ShrinkElem::new(realized).pack(),
+            }
+
+            let mut block = BlockElem::new().with_body(Some(realized));
+            if wrap == RawWrap::Clip {
+                block.push_clip(true);
+            }
+            realized = block.pack();
         }
 
         Ok(realized)
     }
 }
 
+/// How to deal with raw lines that are too long for the available width.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum RawWrap {
+    /// Break long lines. In raw blocks, each broken line is marked with a
+    /// continuation marker.
+    Soft,
+    /// Keep lines intact and clip the parts of a raw block that don't fit
+    /// into it.
+    Clip,
+    /// Keep lines intact and scale down the text of a raw block until its
+    /// longest line fits. Inline raw text is only kept intact.
+    Shrink,
+}
+
+/// Scales down the text of its body until the body's widest line fits into
+/// the region.
+#[elem(Layout)]
+struct ShrinkElem {
+    /// The content to shrink.
+    #[required]
+    body: Content,
+}
+
+impl Layout for ShrinkElem {
+    #[tracing::instrument(name = "ShrinkElem::layout", skip_all)]
+    fn layout(
+        &self,
+        vt: &mut Vt,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let body = self.body();
+        let width = regions.size.x;
+        if width.is_finite() {
+            let pod =
+                Regions::one(Size::new(Abs::inf(), regions.base().y), Axes::splat(false));
+            let natural = body.measure(vt, styles, pod)?.into_frame().width();
+            if natural > width {
+                let size = TextElem::size_in(styles) * (width / natural);
+                let body = body.clone().styled(TextElem::set_size(TextSize(size.into())));
+                return body.layout(vt, styles, regions);
+            }
+        }
+
+        body.layout(vt, styles, regions)
+    }
+}
+
 impl Finalize for RawElem {
     fn finalize(&self, realized: Content, _: StyleChain) -> Content {
         let mut styles = Styles::new();
@@ -622,6 +752,62 @@ fn styled(piece: &str, foreground: synt::Color, style: synt::Style) -> Content {
     body
 }
 
+/// Determine the byte ranges of the changed words in the lines of a diff.
+///
+/// A run of removed lines that is directly followed by an equally long run of
+/// added lines is compared pairwise. Lines that have nothing in common are not
+/// highlighted.
+fn diff_changes(lines: &[&str]) -> Vec<Option<Range<usize>>> {
+    let removed = |line: &str| line.starts_with('-') && !line.starts_with("---");
+    let added = |line: &str| line.starts_with('+') && !line.starts_with("+++");
+
+    let mut changes = vec![None; lines.len()];
+    let mut i = 0;
+    while i < lines.len() {
+        let removals = lines[i..].iter().take_while(|line| removed(line)).count();
+        let additions =
+            lines[i + removals..].iter().take_while(|line| added(line)).count();
+        if removals > 0 && removals == additions {
+            for k in 0..removals {
+                let (old, new) = word_changes(lines[i + k], lines[i + k + removals]);
+                changes[i + k] = old;
+                changes[i + k + removals] = new;
+            }
+        }
+        i += (removals + additions).max(1);
+    }
+
+    changes
+}
+
+/// Find the changed words between an old and a new line of a diff, skipping
+/// the leading `-` and `+`.
+fn word_changes(old: &str, new: &str) -> (Option<Range<usize>>, Option<Range<usize>>) {
+    let a: Vec<&str> = old[1..].split_word_bounds().collect();
+    let b: Vec<&str> = new[1..].split_word_bounds().collect();
+
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    if prefix == 0 && suffix == 0 {
+        return (None, None);
+    }
+
+    let range = |line: &str, words: &[&str]| {
+        let start = 1 + words[..prefix].iter().map(|w| w.len()).sum::<usize>();
+        let end = line.len()
+            - words[words.len() - suffix..].iter().map(|w| w.len()).sum::<usize>();
+        (start < end).then_some(start..end)
+    };
+
+    (range(old, &a), range(new, &b))
+}
+
 fn to_typst(synt::Color { r, g, b, a }: synt::Color) -> Color {
     Color::from_u8(r, g, b, a)
 }
@@ -765,6 +951,8 @@ pub static THEME: Lazy<synt::Theme> = Lazy::new(|| synt::Theme {
         item("markup.italic", None, Some(synt::FontStyle::ITALIC)),
         item("markup.underline", None, Some(synt::FontStyle::UNDERLINE)),
         item("markup.raw", Some("#818181"), None),
+        item("markup.inserted", Some("#298e0d"), None),
+        item("markup.deleted", Some("#d73a49"), None),
         item("string.other.math.typst", None, None),
         item("punctuation.definition.math", Some("#298e0d"), None),
         item("keyword.operator.math", Some("#1d6c76"), None),
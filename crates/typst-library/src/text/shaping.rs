@@ -416,10 +416,23 @@ impl<'a> ShapedText<'a> {
 
     /// Push a hyphen to end of the text.
     pub fn push_hyphen(&mut self, vt: &Vt, fallback: bool) {
+        self.push_char(vt, fallback, '-');
+    }
+
+    /// Push a continuation marker to the end of the text, marking that the
+    /// line continues on the next one.
+    pub fn push_continuation(&mut self, vt: &Vt, fallback: bool) {
+        self.push_char(vt, fallback, '↩');
+    }
+
+    /// Push a single character to the end of the text.
+    fn push_char(&mut self, vt: &Vt, fallback: bool, c: char) {
         let world = vt.world;
         let book = world.book();
+        let mut buf = [0; 4];
+        let text = &*c.encode_utf8(&mut buf);
         let fallback_func = if fallback {
-            Some(|| book.select_fallback(None, self.variant, "-"))
+            Some(|| book.select_fallback(None, self.variant, text))
         } else {
             None
         };
@@ -431,7 +444,7 @@ impl<'a> ShapedText<'a> {
         chain.find_map(|id| {
             let font = world.font(id)?;
             let ttf = font.ttf();
-            let glyph_id = ttf.glyph_index('-')?;
+            let glyph_id = ttf.glyph_index(c)?;
             let x_advance = font.to_em(ttf.glyph_hor_advance(glyph_id)?);
             let range = self
                 .glyphs
@@ -452,7 +465,7 @@ impl<'a> ShapedText<'a> {
                 adjustability: Adjustability::default(),
                 range,
                 safe_to_break: true,
-                c: '-',
+                c,
                 span: (Span::detached(), 0),
                 is_justifiable: false,
                 script: Script::Common,
@@ -10,7 +10,7 @@ use crate::text::families;
 
 /// A raster or vector graphic.
 ///
-/// Supported formats are PNG, JPEG, GIF and SVG.
+/// Supported formats are PNG, JPEG, GIF, WebP and SVG.
 ///
 /// _Note:_ Work on SVG export is ongoing and there might be visual inaccuracies
 /// in the resulting PDF. Make sure to double-check embedded SVG images. If you
@@ -63,6 +63,21 @@ pub struct ImageElem {
     /// How the image should adjust itself to a given area.
     #[default(ImageFit::Cover)]
     pub fit: ImageFit,
+
+    /// How much to cut off each side of the image, relative to the image's
+    /// size. The remaining part is then sized and fitted like a full image.
+    ///
+    /// To rotate an image, use the [`rotate`]($rotate) function.
+    ///
+    /// ```example
+    /// #image("tiger.jpg", width: 40%)
+    /// #image(
+    ///   "tiger.jpg",
+    ///   width: 40%,
+    ///   crop: (left: 30%, right: 20%, top: 10%),
+    /// )
+    /// ```
+    pub crop: Sides<Option<Ratio>>,
 }
 
 #[scope]
@@ -98,6 +113,9 @@ impl ImageElem {
         /// How the image should adjust itself to a given area.
         #[named]
         fit: Option<ImageFit>,
+        /// How much to cut off each side of the image.
+        #[named]
+        crop: Option<Sides<Option<Ratio>>>,
     ) -> StrResult<Content> {
         let mut elem = ImageElem::new(EcoString::new(), data);
         if let Some(format) = format {
@@ -115,6 +133,9 @@ impl ImageElem {
         if let Some(fit) = fit {
             elem.push_fit(fit);
         }
+        if let Some(crop) = crop {
+            elem.push_crop(crop);
+        }
         Ok(elem.pack())
     }
 }
@@ -143,6 +164,7 @@ impl Layout for ImageElem {
                     "png" => ImageFormat::Raster(RasterFormat::Png),
                     "jpg" | "jpeg" => ImageFormat::Raster(RasterFormat::Jpg),
                     "gif" => ImageFormat::Raster(RasterFormat::Gif),
+                    "webp" => ImageFormat::Raster(RasterFormat::Webp),
                     "svg" | "svgz" => ImageFormat::Vector(VectorFormat::Svg),
                     _ => match &data {
                         Readable::Str(_) => ImageFormat::Vector(VectorFormat::Svg),
@@ -172,9 +194,24 @@ impl Layout for ImageElem {
         let expand = sizing.as_ref().map(Smart::is_custom) | regions.expand;
         let region_ratio = region.x / region.y;
 
+        // Determine which part of the image remains visible after cropping.
+        let crop = self.crop(styles).map(|side| side.unwrap_or_default().get());
+        if [crop.left, crop.top, crop.right, crop.bottom]
+            .iter()
+            .any(|side| !(0.0..=1.0).contains(side))
+        {
+            bail!(self.span(), "cropping must be between 0% and 100% on each side");
+        }
+
+        let visible =
+            Axes::new(1.0 - crop.left - crop.right, 1.0 - crop.top - crop.bottom);
+        if visible.x <= 0.0 || visible.y <= 0.0 {
+            bail!(self.span(), "cropping must leave part of the image visible");
+        }
+
         // Find out whether the image is wider or taller than the target size.
-        let pxw = image.width() as f64;
-        let pxh = image.height() as f64;
+        let pxw = image.width() as f64 * visible.x;
+        let pxh = image.height() as f64 * visible.y;
         let px_ratio = pxw / pxh;
         let wide = px_ratio > region_ratio;
 
@@ -206,7 +243,16 @@ impl Layout for ImageElem {
         // the frame to the target size, center aligning the image in the
         // process.
         let mut frame = Frame::soft(fitted);
-        frame.push(Point::zero(), FrameItem::Image(image, fitted, self.span()));
+        if visible == Axes::splat(1.0) {
+            frame.push(Point::zero(), FrameItem::Image(image, fitted, self.span()));
+        } else {
+            // Scale the full image such that its visible part has the fitted
+            // size and cut off the rest.
+            let full = Size::new(fitted.x / visible.x, fitted.y / visible.y);
+            let pos = Point::new(-full.x * crop.left, -full.y * crop.top);
+            frame.push(pos, FrameItem::Image(image, full, self.span()));
+            frame.clip(Path::rect(fitted));
+        }
         frame.resize(target, Axes::splat(FixedAlign::Center));
 
         // Create a clipping group if only part of the image should be visible.
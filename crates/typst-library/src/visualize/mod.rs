@@ -19,6 +19,7 @@ pub(super) fn define(global: &mut Scope) {
     global.category("visualize");
     global.define_type::<Color>();
     global.define_type::<Gradient>();
+    global.define_type::<SpotColor>();
     global.define_type::<Stroke>();
     global.define_elem::<ImageElem>();
     global.define_elem::<LineElem>();
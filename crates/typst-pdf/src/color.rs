@@ -1,7 +1,9 @@
+use ecow::{eco_format, EcoString};
 use once_cell::sync::Lazy;
-use pdf_writer::types::DeviceNSubtype;
+use pdf_writer::types::{ColorSpaceOperand, DeviceNSubtype};
 use pdf_writer::{writers, Chunk, Dict, Filter, Name, Ref};
-use typst::geom::{Color, ColorSpace, Paint};
+use typst::diag::{bail, StrResult};
+use typst::geom::{Color, ColorSpace, Paint, SpotColor};
 
 use crate::deflate;
 use crate::page::{PageContext, Transforms};
@@ -48,6 +50,7 @@ pub struct ColorSpaces {
     hsv: Option<Ref>,
     hsl: Option<Ref>,
     use_linear_rgb: bool,
+    spots: Vec<(SpotColor, Ref)>,
 }
 
 impl ColorSpaces {
@@ -93,6 +96,18 @@ impl ColorSpaces {
         self.use_linear_rgb = true;
     }
 
+    /// Get the resource name of a spot color's separation color space.
+    pub fn spot(&mut self, spot: &SpotColor, alloc: &mut Ref) -> EcoString {
+        let index = match self.spots.iter().position(|(s, _)| s == spot) {
+            Some(index) => index,
+            None => {
+                self.spots.push((spot.clone(), alloc.bump()));
+                self.spots.len() - 1
+            }
+        };
+        eco_format!("Sp{index}")
+    }
+
     /// Write the color space on usage.
     pub fn write(
         &mut self,
@@ -162,11 +177,36 @@ impl ColorSpaces {
         if self.use_linear_rgb {
             self.write(ColorSpace::LinearRgb, spaces.insert(LINEAR_SRGB).start(), alloc);
         }
+
+        // Spot colors are separations whose tint is mapped onto the fallback
+        // color.
+        for (i, (spot, tint)) in self.spots.clone().into_iter().enumerate() {
+            let name = eco_format!("Sp{i}");
+            let space: writers::ColorSpace = spaces.insert(Name(name.as_bytes())).start();
+            let mut separation = space.separation(Name(spot.name().as_bytes()));
+            self.write(spot_alternate(&spot), separation.alternate_color_space(), alloc);
+            separation.tint_ref(tint);
+        }
     }
 
     /// Write the necessary color spaces functions and ICC profiles to the
     /// PDF file.
     pub fn write_functions(&self, chunk: &mut Chunk) {
+        // Write the tint transforms of the spot colors, which go from no ink
+        // at all to the full fallback color.
+        for (spot, tint) in &self.spots {
+            let (c0, c1) = match spot_alternate(spot) {
+                ColorSpace::Cmyk => (vec![0.0; 4], spot.fallback().to_cmyk().to_vec4()),
+                _ => (vec![1.0; 3], spot.fallback().to_rgb().to_vec4()),
+            };
+            chunk
+                .exponential_function(*tint)
+                .domain([0.0, 1.0])
+                .c0(c0.iter().copied())
+                .c1(c1[..c0.len()].iter().copied())
+                .n(1.0);
+        }
+
         // Write the Oklab function & color space.
         if let Some(oklab) = self.oklab {
             chunk
@@ -236,6 +276,114 @@ fn minify(source: &str) -> String {
     buf
 }
 
+/// An ICC profile describing the intended output device of a PDF, for example
+/// a specific printing condition.
+///
+/// Embedding it as the document's output intent lets print workflows convert
+/// the document's colors to the target color space as intended. The colors
+/// themselves are always tagged with their source ICC profile (sRGB or gray),
+/// so the conversion from source to target is fully defined.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct OutputIntent {
+    /// The deflated ICC profile.
+    profile: Vec<u8>,
+    /// The number of color components of the profile's color space.
+    components: i32,
+    /// The profile's description, identifying the output condition.
+    description: EcoString,
+}
+
+impl OutputIntent {
+    /// Create an output intent from the raw bytes of an ICC profile.
+    ///
+    /// The profile must describe an output or display device and have a
+    /// gray, RGB, or CMYK color space.
+    pub fn new(profile: &[u8]) -> StrResult<Self> {
+        if profile.len() < 132 || &profile[36..40] != b"acsp" {
+            bail!("invalid ICC profile");
+        }
+
+        if read_u32(profile, 0) != Some(profile.len() as u32) {
+            bail!("invalid ICC profile (size in header does not match)");
+        }
+
+        if !matches!(&profile[12..16], b"prtr" | b"mntr") {
+            bail!("ICC profile does not describe an output device");
+        }
+
+        let components = match &profile[16..20] {
+            b"GRAY" => 1,
+            b"RGB " => 3,
+            b"CMYK" => 4,
+            _ => bail!("ICC profile has unsupported color space"),
+        };
+
+        let Some(description) = icc_description(profile) else {
+            bail!("ICC profile has no description");
+        };
+
+        Ok(Self { profile: deflate(profile), components, description })
+    }
+
+    /// The profile's description, which identifies the output condition.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Write the ICC profile stream.
+    pub(super) fn write(&self, chunk: &mut Chunk, id: Ref) {
+        chunk
+            .icc_profile(id, &self.profile)
+            .n(self.components)
+            .filter(Filter::FlateDecode);
+    }
+}
+
+/// Read the description of an ICC profile from its `desc` tag, which is
+/// either a version 2 text description or a version 4 multi-localized
+/// unicode text.
+fn icc_description(profile: &[u8]) -> Option<EcoString> {
+    let count = read_u32(profile, 128)? as usize;
+    let entry = (0..count)
+        .map(|i| 132 + 12 * i)
+        .take_while(|&entry| entry + 12 <= profile.len())
+        .find(|&entry| &profile[entry..entry + 4] == b"desc")?;
+
+    let offset = read_u32(profile, entry + 4)? as usize;
+    let size = read_u32(profile, entry + 8)? as usize;
+    let tag = profile.get(offset..offset.checked_add(size)?)?;
+    let text: EcoString = match tag.get(..4)? {
+        b"desc" => {
+            let len = read_u32(tag, 8)? as usize;
+            let ascii = tag.get(12..len.checked_add(12)?)?;
+            ascii.iter().take_while(|&&b| b != 0).map(|&b| b as char).collect()
+        }
+        b"mluc" => {
+            // Use the first record, which is the default language.
+            if read_u32(tag, 8)? == 0 {
+                return None;
+            }
+            let len = read_u32(tag, 20)? as usize;
+            let start = read_u32(tag, 24)? as usize;
+            let units: Vec<u16> = tag
+                .get(start..start.checked_add(len)?)?
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16(&units).ok()?.trim_end_matches('\0').into()
+        }
+        _ => return None,
+    };
+
+    (!text.trim().is_empty()).then_some(text)
+}
+
+/// Read a big-endian `u32` at the given offset.
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes(bytes.try_into().ok()?))
+}
+
 /// Encodes the color into four f32s, which can be used in a PDF file.
 /// Ensures that the values are in the range [0.0, 1.0].
 ///
@@ -294,6 +442,7 @@ impl PaintEncode for Paint {
         match self {
             Self::Solid(c) => c.set_as_fill(ctx, on_text, transforms),
             Self::Gradient(gradient) => gradient.set_as_fill(ctx, on_text, transforms),
+            Self::Spot(spot) => spot.set_as_fill(ctx, on_text, transforms),
         }
     }
 
@@ -301,10 +450,40 @@ impl PaintEncode for Paint {
         match self {
             Self::Solid(c) => c.set_as_stroke(ctx, transforms),
             Self::Gradient(gradient) => gradient.set_as_stroke(ctx, transforms),
+            Self::Spot(spot) => spot.set_as_stroke(ctx, transforms),
         }
     }
 }
 
+impl PaintEncode for SpotColor {
+    fn set_as_fill(&self, ctx: &mut PageContext, _: bool, _: Transforms) {
+        ctx.reset_fill_color_space();
+
+        let name = ctx.parent.colors.spot(self, &mut ctx.parent.alloc);
+        let operand = ColorSpaceOperand::Named(Name(name.as_bytes()));
+        ctx.content.set_fill_color_space(operand);
+        ctx.content.set_fill_color([1.0]);
+    }
+
+    fn set_as_stroke(&self, ctx: &mut PageContext, _: Transforms) {
+        ctx.reset_stroke_color_space();
+
+        let name = ctx.parent.colors.spot(self, &mut ctx.parent.alloc);
+        let operand = ColorSpaceOperand::Named(Name(name.as_bytes()));
+        ctx.content.set_stroke_color_space(operand);
+        ctx.content.set_stroke_color([1.0]);
+    }
+}
+
+/// The color space a spot color falls back to in viewers without support for
+/// the ink.
+fn spot_alternate(spot: &SpotColor) -> ColorSpace {
+    match spot.fallback() {
+        Color::Cmyk(_) => ColorSpace::Cmyk,
+        _ => ColorSpace::Srgb,
+    }
+}
+
 impl PaintEncode for Color {
     fn set_as_fill(&self, ctx: &mut PageContext, _: bool, _: Transforms) {
         match self {
@@ -480,3 +659,101 @@ impl QuantizedColor for f32 {
         color.clamp(min, max)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use typst::doc::{Document, Frame};
+    use typst::geom::{Abs, Size};
+
+    use super::*;
+    use crate::{pdf_with_options, PdfOptions};
+
+    /// Build an ICC profile with the given device class, color space, and
+    /// version 2 description.
+    fn profile(class: &[u8; 4], space: &[u8; 4], description: &str) -> Vec<u8> {
+        let mut tag = b"desc\0\0\0\0".to_vec();
+        tag.extend((description.len() as u32 + 1).to_be_bytes());
+        tag.extend(description.as_bytes());
+        tag.push(0);
+
+        let mut data = vec![0; 128];
+        data[12..16].copy_from_slice(class);
+        data[16..20].copy_from_slice(space);
+        data[36..40].copy_from_slice(b"acsp");
+        data.extend(1u32.to_be_bytes());
+        data.extend(b"desc");
+        data.extend(144u32.to_be_bytes());
+        data.extend((tag.len() as u32).to_be_bytes());
+        data.extend(tag);
+
+        let len = data.len() as u32;
+        data[..4].copy_from_slice(&len.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn test_output_intent_description() {
+        let srgb = OutputIntent::new(include_bytes!("icc/sRGB-v4.icc")).unwrap();
+        assert_eq!(srgb.description(), "sRGB");
+        assert_eq!(srgb.components, 3);
+
+        let gray = OutputIntent::new(include_bytes!("icc/sGrey-v4.icc")).unwrap();
+        assert_eq!(gray.description(), "sGry");
+        assert_eq!(gray.components, 1);
+
+        let cmyk = OutputIntent::new(&profile(b"prtr", b"CMYK", "Coated FOGRA39"));
+        assert_eq!(cmyk.unwrap().description(), "Coated FOGRA39");
+    }
+
+    #[test]
+    fn test_output_intent_validates_header() {
+        let error = |data: &[u8]| OutputIntent::new(data).unwrap_err();
+        let valid = profile(b"prtr", b"CMYK", "Coated FOGRA39");
+        assert!(OutputIntent::new(&valid).is_ok());
+
+        assert_eq!(error(&valid[..100]), "invalid ICC profile");
+
+        let mut magic = valid.clone();
+        magic[36..40].copy_from_slice(b"abcd");
+        assert_eq!(error(&magic), "invalid ICC profile");
+
+        let mut truncated = valid.clone();
+        truncated.pop();
+        assert_eq!(
+            error(&truncated),
+            "invalid ICC profile (size in header does not match)"
+        );
+
+        assert_eq!(
+            error(&profile(b"link", b"CMYK", "Device link")),
+            "ICC profile does not describe an output device",
+        );
+        assert_eq!(
+            error(&profile(b"prtr", b"Lab ", "Lab")),
+            "ICC profile has unsupported color space",
+        );
+        assert_eq!(
+            error(&profile(b"prtr", b"CMYK", "")),
+            "ICC profile has no description",
+        );
+    }
+
+    #[test]
+    fn test_output_intent_export() {
+        let intent = OutputIntent::new(&profile(b"prtr", b"CMYK", "Coated FOGRA39"));
+        let options = PdfOptions {
+            output_intent: Some(intent.unwrap()),
+            ..PdfOptions::default()
+        };
+
+        let page = Frame::soft(Size::splat(Abs::pt(10.0)));
+        let document = Document { pages: vec![page], ..Document::default() };
+        let buf = pdf_with_options(&document, None, None, &options);
+        let text = String::from_utf8_lossy(&buf);
+        assert!(text.contains("/S /GTS_PDFX"));
+        assert!(text.contains("/OutputConditionIdentifier (Coated FOGRA39)"));
+        assert!(text.contains("/Info (Coated FOGRA39)"));
+        assert!(text.contains("/N 4"));
+        assert!(!text.contains("(Custom)"));
+    }
+}
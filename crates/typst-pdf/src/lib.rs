@@ -7,16 +7,18 @@ mod gradient;
 mod image;
 mod outline;
 mod page;
+mod signature;
 
 use std::cmp::Eq;
 use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
+use std::num::NonZeroUsize;
 
 use base64::Engine;
 use ecow::{eco_format, EcoString};
 use pdf_writer::types::Direction;
-use pdf_writer::{Finish, Name, Pdf, Ref, TextStr};
-use typst::doc::{Document, Lang};
+use pdf_writer::{Finish, Name, Pdf, Ref, Str, TextStr};
+use typst::doc::{Document, Frame, Lang};
 use typst::eval::Datetime;
 use typst::font::Font;
 use typst::geom::{Abs, Dir, Em};
@@ -29,7 +31,10 @@ use crate::color::ColorSpaces;
 use crate::extg::ExtGState;
 use crate::gradient::PdfGradient;
 use crate::image::EncodedImage;
-use crate::page::Page;
+use crate::page::{Form, Page};
+
+pub use crate::color::OutputIntent;
+pub use crate::signature::SIGNATURE_SIZE;
 
 /// Export a document into a PDF file.
 ///
@@ -51,24 +56,82 @@ pub fn pdf(
     ident: Option<&str>,
     timestamp: Option<Datetime>,
 ) -> Vec<u8> {
-    let mut ctx = PdfContext::new(document);
-    page::construct_pages(&mut ctx, &document.pages);
+    pdf_with_options(document, ident, timestamp, &PdfOptions::default())
+}
+
+/// Additional options for PDF export.
+#[derive(Debug, Default, Clone)]
+pub struct PdfOptions {
+    /// An ICC profile to embed as the document's output intent, describing the
+    /// device the document is meant to be printed on.
+    pub output_intent: Option<OutputIntent>,
+    /// Whether to reserve an empty signature field on the first page. Has no
+    /// effect on documents without pages.
+    ///
+    /// The field's byte range is already filled in, so that external tools
+    /// can insert a detached PKCS#7 signature of up to
+    /// [`SIGNATURE_SIZE`] bytes without rewriting the file.
+    pub signature_placeholder: bool,
+    /// The numbers of the pages to export, counting from one. Exports all
+    /// pages if `None`.
+    ///
+    /// The document is still introspected as a whole, so page labels and
+    /// references keep reflecting the full document. Links, outline entries,
+    /// and named destinations that point to an exported page lead to its new
+    /// position. Those that point to a page that is not exported are left out.
+    pub pages: Option<Vec<NonZeroUsize>>,
+}
+
+/// Export a document into a PDF file with additional options.
+///
+/// Works like [`pdf`], but respects the given [`PdfOptions`].
+#[tracing::instrument(skip_all)]
+pub fn pdf_with_options(
+    document: &Document,
+    ident: Option<&str>,
+    timestamp: Option<Datetime>,
+    options: &PdfOptions,
+) -> Vec<u8> {
+    let mut ctx = PdfContext::new(document, options);
+    let frames: Vec<&Frame> = document
+        .pages
+        .iter()
+        .zip(&ctx.page_indices.0)
+        .filter(|(_, index)| index.is_some())
+        .map(|(frame, _)| frame)
+        .collect();
+    page::construct_pages(&mut ctx, &frames);
     font::write_fonts(&mut ctx);
     image::write_images(&mut ctx);
     gradient::write_gradients(&mut ctx);
     extg::write_external_graphics_states(&mut ctx);
     page::write_page_tree(&mut ctx);
     write_catalog(&mut ctx, ident, timestamp);
-    ctx.pdf.finish()
+
+    let signature_offset = ctx.signature_offset;
+    let mut buf = ctx.pdf.finish();
+    if let Some(offset) = signature_offset {
+        signature::fill_byte_range(&mut buf, offset);
+    }
+    buf
 }
 
 /// Context for exporting a whole PDF document.
 struct PdfContext<'a> {
     /// The document that we're currently exporting.
     document: &'a Document,
+    /// The output intent to embed, if any.
+    output_intent: Option<&'a OutputIntent>,
+    /// The ID of the reserved signature field, if any.
+    signature_field: Option<Ref>,
+    /// The byte offset of the written signature value, if any.
+    signature_offset: Option<usize>,
     /// An introspector for the document, used to resolve locations links and
     /// the document outline.
     introspector: Introspector,
+    /// For each page of the document, its index among the exported pages, if
+    /// it is exported.
+    page_indices: PageIndices,
 
     /// The writer we are writing the PDF into.
     pdf: Pdf,
@@ -89,6 +152,8 @@ struct PdfContext<'a> {
     alloc: Ref,
     /// The ID of the page tree.
     page_tree_ref: Ref,
+    /// The ID of the resource dictionary shared by pages and form XObjects.
+    resources_ref: Ref,
     /// The IDs of written pages.
     page_refs: Vec<Ref>,
     /// The IDs of written fonts.
@@ -97,6 +162,8 @@ struct PdfContext<'a> {
     image_refs: Vec<Ref>,
     /// The IDs of written gradients.
     gradient_refs: Vec<Ref>,
+    /// The IDs of written form XObjects.
+    form_refs: Vec<Ref>,
     /// The IDs of written external graphics states.
     ext_gs_refs: Vec<Ref>,
     /// Handles color space writing.
@@ -112,25 +179,42 @@ struct PdfContext<'a> {
     gradient_map: Remapper<PdfGradient>,
     /// Deduplicates external graphics states used across the document.
     extg_map: Remapper<ExtGState>,
+    /// Groups that repeat across pages, keyed by their contents and position.
+    /// The form is `None` until it has been written.
+    forms: HashMap<u128, Option<Form>>,
 }
 
 impl<'a> PdfContext<'a> {
-    fn new(document: &'a Document) -> Self {
+    fn new(document: &'a Document, options: &'a PdfOptions) -> Self {
         let mut alloc = Ref::new(1);
         let page_tree_ref = alloc.bump();
+        let resources_ref = alloc.bump();
+        let page_indices =
+            PageIndices::new(document.pages.len(), options.pages.as_deref());
+
+        // The signature field's widget needs a page to live on.
+        let has_pages = page_indices.0.iter().any(Option::is_some);
+        let signature_field =
+            (options.signature_placeholder && has_pages).then(|| alloc.bump());
         Self {
             document,
+            output_intent: options.output_intent.as_ref(),
+            signature_field,
+            signature_offset: None,
             introspector: Introspector::new(&document.pages),
+            page_indices,
             pdf: Pdf::new(),
             pages: vec![],
             glyph_sets: HashMap::new(),
             languages: HashMap::new(),
             alloc,
             page_tree_ref,
+            resources_ref,
             page_refs: vec![],
             font_refs: vec![],
             image_refs: vec![],
             gradient_refs: vec![],
+            form_refs: vec![],
             ext_gs_refs: vec![],
             colors: ColorSpaces::default(),
             font_map: Remapper::new(),
@@ -138,10 +222,40 @@ impl<'a> PdfContext<'a> {
             image_deferred_map: HashMap::default(),
             gradient_map: Remapper::new(),
             extg_map: Remapper::new(),
+            forms: HashMap::new(),
         }
     }
 }
 
+/// Maps the pages of a document to the pages that are exported.
+struct PageIndices(Vec<Option<usize>>);
+
+impl PageIndices {
+    /// Map the pages of a document with `len` pages, exporting only the given
+    /// page numbers if there is a selection.
+    fn new(len: usize, selection: Option<&[NonZeroUsize]>) -> Self {
+        let mut exported = 0;
+        Self(
+            (1..=len)
+                .map(|nr| {
+                    let selected = selection
+                        .map_or(true, |pages| pages.iter().any(|page| page.get() == nr));
+                    selected.then(|| {
+                        exported += 1;
+                        exported - 1
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// The index among the exported pages of the page with the given number,
+    /// if it is exported.
+    fn get(&self, page: NonZeroUsize) -> Option<usize> {
+        self.0.get(page.get() - 1).copied().flatten()
+    }
+}
+
 /// Write the document catalog.
 #[tracing::instrument(skip_all)]
 fn write_catalog(ctx: &mut PdfContext, ident: Option<&str>, timestamp: Option<Datetime>) {
@@ -163,6 +277,9 @@ fn write_catalog(ctx: &mut PdfContext, ident: Option<&str>, timestamp: Option<Da
     // Write the page labels.
     let page_labels = page::write_page_labels(ctx);
 
+    // Write the named destinations.
+    let destinations = page::write_named_destinations(ctx);
+
     // Write the document information.
     let mut info = ctx.pdf.document_info(ctx.alloc.bump());
     let mut xmp = XmpWriter::new();
@@ -201,7 +318,7 @@ fn write_catalog(ctx: &mut PdfContext, ident: Option<&str>, timestamp: Option<Da
     }
 
     info.finish();
-    xmp.num_pages(ctx.document.pages.len() as u32);
+    xmp.num_pages(ctx.pages.len() as u32);
     xmp.format("application/pdf");
     xmp.language(ctx.languages.keys().map(|lang| LangId(lang.as_str())));
 
@@ -232,6 +349,18 @@ fn write_catalog(ctx: &mut PdfContext, ident: Option<&str>, timestamp: Option<Da
         .pair(Name(b"Type"), Name(b"Metadata"))
         .pair(Name(b"Subtype"), Name(b"XML"));
 
+    // Write the output intent's ICC profile.
+    let output_intent = ctx.output_intent.map(|intent| {
+        let id = ctx.alloc.bump();
+        intent.write(&mut ctx.pdf, id);
+        (intent, id)
+    });
+
+    // Write the signature field.
+    if let Some(field) = ctx.signature_field {
+        ctx.signature_offset = Some(signature::write_signature_field(ctx, field));
+    }
+
     // Write the document catalog.
     let mut catalog = ctx.pdf.catalog(ctx.alloc.bump());
     catalog.pages(ctx.page_tree_ref);
@@ -251,9 +380,36 @@ fn write_catalog(ctx: &mut PdfContext, ident: Option<&str>, timestamp: Option<Da
         catalog.outlines(outline_root_id);
     }
 
+    if !destinations.is_empty() {
+        let mut names = catalog.insert(Name(b"Names")).dict();
+        let mut dests = names.insert(Name(b"Dests")).dict();
+        let mut entries = dests.insert(Name(b"Names")).array();
+        for (label, id) in &destinations {
+            entries.item(Str(label.as_str().as_bytes())).item(*id);
+        }
+    }
+
     if let Some(lang) = lang {
         catalog.lang(TextStr(lang.as_str()));
     }
+
+    if let Some(field) = ctx.signature_field {
+        let mut form = catalog.insert(Name(b"AcroForm")).dict();
+        form.insert(Name(b"Fields")).array().item(field);
+        form.pair(Name(b"SigFlags"), 3);
+    }
+
+    if let Some((intent, profile_ref)) = output_intent {
+        let mut intents = catalog.insert(Name(b"OutputIntents")).array();
+        intents
+            .push()
+            .dict()
+            .pair(Name(b"Type"), Name(b"OutputIntent"))
+            .pair(Name(b"S"), Name(b"GTS_PDFX"))
+            .pair(Name(b"OutputConditionIdentifier"), TextStr(intent.description()))
+            .pair(Name(b"Info"), TextStr(intent.description()))
+            .pair(Name(b"DestOutputProfile"), profile_ref);
+    }
 }
 
 /// Compress data with the DEFLATE algorithm.
@@ -380,3 +536,156 @@ impl EmExt for Em {
         1000.0 * self.get() as f32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use typst::doc::{Destination, FrameItem, GroupItem, Meta, Position};
+    use typst::eval::Bytes;
+    use typst::geom::{Color, Geometry, Point, Size};
+    use typst::image::{ImageFormat, VectorFormat};
+    use typst::model::{Content, Locator};
+    use typst::syntax::Span;
+
+    use super::*;
+
+    /// Decompress all streams in a PDF file and concatenate them.
+    fn inflate_streams(buf: &[u8]) -> Vec<u8> {
+        let mut inflated = vec![];
+        let mut rest = buf;
+        while let Some(start) = find(rest, b"stream\n") {
+            rest = &rest[start + 7..];
+            let end = find(rest, b"\nendstream").unwrap();
+            if let Ok(data) = miniz_oxide::inflate::decompress_to_vec_zlib(&rest[..end]) {
+                inflated.extend(data);
+            }
+            rest = &rest[end + 10..];
+        }
+        inflated
+    }
+
+    /// Find the first occurrence of a byte sequence.
+    fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        haystack.windows(needle.len()).position(|window| window == needle)
+    }
+
+    /// Count the occurrences of a byte sequence.
+    fn count(haystack: &[u8], needle: &[u8]) -> usize {
+        haystack
+            .windows(needle.len())
+            .filter(|window| *window == needle)
+            .count()
+    }
+
+    #[test]
+    fn test_page_indices() {
+        let nr = |n| NonZeroUsize::new(n).unwrap();
+        let indices = PageIndices::new(5, Some(&[nr(3), nr(4), nr(5)]));
+        assert_eq!(indices.get(nr(1)), None);
+        assert_eq!(indices.get(nr(3)), Some(0));
+        assert_eq!(indices.get(nr(4)), Some(1));
+        assert_eq!(indices.get(nr(6)), None);
+        assert_eq!(PageIndices::new(2, None).get(nr(2)), Some(1));
+    }
+
+    #[test]
+    fn test_selected_pages_keep_links() {
+        let link = |page| {
+            let pos = Position {
+                page: NonZeroUsize::new(page).unwrap(),
+                point: Point::zero(),
+            };
+            let size = Size::splat(Abs::pt(10.0));
+            FrameItem::Meta(Meta::Link(Destination::Position(pos)), size)
+        };
+
+        // The third page links to the fourth and to the first page.
+        let mut pages = vec![Frame::soft(Size::splat(Abs::pt(100.0))); 5];
+        pages[2].push(Point::zero(), link(4));
+        pages[2].push(Point::zero(), link(1));
+
+        let document = Document { pages, ..Document::default() };
+        let pages = [3, 4, 5].map(|n| NonZeroUsize::new(n).unwrap()).to_vec();
+        let options = PdfOptions { pages: Some(pages), ..PdfOptions::default() };
+        let buf = pdf_with_options(&document, None, None, &options);
+
+        // Only the link to the exported fourth page is kept. It leads to the
+        // second exported page, whose object comes after the page tree, the
+        // resources, and the first page.
+        assert_eq!(count(&buf, b"/Type /Page\n"), 3);
+        assert_eq!(count(&buf, b"/Subtype /Link"), 1);
+        assert_eq!(count(&buf, b"/D [4 0 R"), 1);
+    }
+
+    #[test]
+    fn test_image_alt_text() {
+        let image = |alt: Option<&str>| {
+            let data = Bytes::from_static(
+                b"<svg xmlns='http://www.w3.org/2000/svg' width='10' height='10'/>",
+            );
+            let format = ImageFormat::Vector(VectorFormat::Svg);
+            let image = Image::new(data, format, alt.map(Into::into)).unwrap();
+            FrameItem::Image(image, Size::splat(Abs::pt(10.0)), Span::detached())
+        };
+
+        let mut frame = Frame::soft(Size::splat(Abs::pt(100.0)));
+        frame.push(Point::zero(), image(Some("A small square")));
+        frame.push(Point::zero(), image(Some("Ein Quadrat für dich")));
+        frame.push(Point::zero(), image(None));
+
+        let document = Document { pages: vec![frame], ..Document::default() };
+        let content = inflate_streams(&pdf(&document, None, None));
+
+        // Non-ASCII alt text is written as UTF-16 with a byte order mark.
+        let utf16: Vec<u8> = [0xFE, 0xFF]
+            .into_iter()
+            .chain("Ein Quadrat für dich".encode_utf16().flat_map(u16::to_be_bytes))
+            .collect();
+        assert_eq!(count(&content, b"/Alt (A small square)"), 1);
+        assert_eq!(count(&content, &utf16), 1);
+        assert_eq!(count(&content, b"BDC"), 2);
+        assert_eq!(count(&content, b"EMC"), 2);
+    }
+
+    #[test]
+    fn test_repeated_headers_are_shared() {
+        let mut locator = Locator::new();
+        let size = Size::new(Abs::pt(100.0), Abs::pt(10.0));
+        let link =
+            |url: &str| FrameItem::Meta(Meta::Link(Destination::Url(url.into())), size);
+
+        // Each header has its own location, like the counter in it would.
+        let mut header = |i: u128, linked: bool| {
+            let shape = Geometry::Rect(size).filled(Color::BLACK.into());
+            let mut content = Content::empty();
+            content.set_location(locator.locate(i));
+            let mut frame = Frame::soft(size);
+            frame.push(Point::zero(), FrameItem::Shape(shape, Span::detached()));
+            frame.push(Point::zero(), FrameItem::Meta(Meta::Elem(content), size));
+            if linked {
+                frame.push(Point::zero(), link("https://example.com/header"));
+            }
+            FrameItem::Group(GroupItem::new(frame))
+        };
+
+        // The first three pages share their header, the fourth page's header
+        // has a link and must be written inline.
+        let mut pages = vec![];
+        for i in 0..4 {
+            let mut page = Frame::soft(Size::splat(Abs::pt(100.0)));
+            page.push(Point::zero(), header(i, i == 3));
+            page.push(
+                Point::new(Abs::zero(), Abs::pt(50.0)),
+                link("https://example.com"),
+            );
+            pages.push(page);
+        }
+
+        let document = Document { pages, ..Document::default() };
+        let buf = pdf(&document, None, None);
+        let content = inflate_streams(&buf);
+        assert_eq!(count(&buf, b"/Subtype /Form"), 1);
+        assert_eq!(count(&content, b"/Fm0 Do"), 3);
+        assert_eq!(count(&buf, b"/Subtype /Link"), 5);
+        assert_eq!(count(&buf, b"(https://example.com/header)"), 1);
+    }
+}
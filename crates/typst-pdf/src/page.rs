@@ -1,3 +1,4 @@
+use std::collections::{HashMap, HashSet};
 use std::num::NonZeroUsize;
 use std::sync::Arc;
 
@@ -6,8 +7,8 @@ use pdf_writer::types::{
     ActionType, AnnotationType, ColorSpaceOperand, LineCapStyle, LineJoinStyle,
     NumberingStyle,
 };
-use pdf_writer::writers::PageLabel;
-use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref, Str, TextStr};
+use pdf_writer::writers::{Annotation, PageLabel, Resources};
+use pdf_writer::{Array, Content, Filter, Finish, Name, Null, Rect, Ref, Str, TextStr};
 use typst::doc::{
     Destination, Frame, FrameItem, GroupItem, Meta, PdfPageLabel, PdfPageLabelStyle,
     TextItem,
@@ -18,6 +19,8 @@ use typst::geom::{
     Ratio, Shape, Size, Transform,
 };
 use typst::image::Image;
+use typst::model::Label;
+use typst::util::hash128;
 
 use crate::color::PaintEncode;
 use crate::extg::ExtGState;
@@ -26,7 +29,25 @@ use crate::{deflate, AbsExt, EmExt, PdfContext};
 
 /// Construct page objects.
 #[tracing::instrument(skip_all)]
-pub(crate) fn construct_pages(ctx: &mut PdfContext, frames: &[Frame]) {
+pub(crate) fn construct_pages(ctx: &mut PdfContext, frames: &[&Frame]) {
+    // Find groups that repeat across pages, like headers, footers, and
+    // backgrounds, so that we can write them once as form XObjects.
+    let mut counts = HashMap::new();
+    for frame in frames {
+        for (pos, item) in frame.items() {
+            let FrameItem::Group(group) = item else { continue };
+            if let Some(key) = form_key(frame.size(), *pos, group) {
+                *counts.entry(key).or_insert(0) += 1;
+            }
+        }
+    }
+
+    ctx.forms = counts
+        .into_iter()
+        .filter(|&(_, count)| count > 1)
+        .map(|(key, _)| (key, None))
+        .collect();
+
     for frame in frames {
         construct_page(ctx, frame);
     }
@@ -85,12 +106,14 @@ pub(crate) fn write_page_tree(ctx: &mut PdfContext) {
         write_page(ctx, i);
     }
 
-    let mut pages = ctx.pdf.pages(ctx.page_tree_ref);
-    pages
+    ctx.pdf
+        .pages(ctx.page_tree_ref)
         .count(ctx.page_refs.len() as i32)
-        .kids(ctx.page_refs.iter().copied());
+        .kids(ctx.page_refs.iter().copied())
+        .pair(Name(b"Resources"), ctx.resources_ref);
 
-    let mut resources = pages.resources();
+    // The resources are shared by all pages and form XObjects.
+    let mut resources = ctx.pdf.indirect(ctx.resources_ref).start::<Resources>();
     ctx.colors
         .write_color_spaces(resources.color_spaces(), &mut ctx.alloc);
 
@@ -108,6 +131,11 @@ pub(crate) fn write_page_tree(ctx: &mut PdfContext) {
         images.pair(Name(name.as_bytes()), image_ref);
     }
 
+    for (i, &form_ref) in ctx.form_refs.iter().enumerate() {
+        let name = eco_format!("Fm{}", i);
+        images.pair(Name(name.as_bytes()), form_ref);
+    }
+
     images.finish();
 
     let mut patterns = resources.patterns();
@@ -126,7 +154,6 @@ pub(crate) fn write_page_tree(ctx: &mut PdfContext) {
     ext_gs_states.finish();
 
     resources.finish();
-    pages.finish();
 
     // Write all of the functions used by the document.
     ctx.colors.write_functions(&mut ctx.pdf);
@@ -156,15 +183,11 @@ fn write_page(ctx: &mut PdfContext, i: usize) {
             .srgb();
     }
 
-    let mut annotations = page_writer.annotations();
+    let mut annotations = page_writer.insert(Name(b"Annots")).array();
     for (dest, rect) in &page.links {
-        let mut annotation = annotations.push();
-        annotation.subtype(AnnotationType::Link).rect(*rect);
-        annotation.border(0.0, 0.0, 0.0, None);
-
         let pos = match dest {
             Destination::Url(uri) => {
-                annotation
+                link_annotation(&mut annotations, *rect)
                     .action()
                     .action_type(ActionType::Uri)
                     .uri(Str(uri.as_bytes()));
@@ -174,16 +197,20 @@ fn write_page(ctx: &mut PdfContext, i: usize) {
             Destination::Location(loc) => ctx.introspector.position(*loc),
         };
 
-        let index = pos.page.get() - 1;
+        // Links to pages that are not exported are left out.
+        let Some(index) = ctx.page_indices.get(pos.page) else { continue };
         let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
-        if let Some(page) = ctx.pages.get(index) {
-            annotation
-                .action()
-                .action_type(ActionType::GoTo)
-                .destination()
-                .page(ctx.page_refs[index])
-                .xyz(pos.point.x.to_f32(), (page.size.y - y).to_f32(), None);
-        }
+        link_annotation(&mut annotations, *rect)
+            .action()
+            .action_type(ActionType::GoTo)
+            .destination()
+            .page(ctx.page_refs[index])
+            .xyz(pos.point.x.to_f32(), (ctx.pages[index].size.y - y).to_f32(), None);
+    }
+
+    // The reserved signature field lives on the first page.
+    if let Some(field) = ctx.signature_field.filter(|_| i == 0) {
+        annotations.item(field);
     }
 
     annotations.finish();
@@ -193,6 +220,14 @@ fn write_page(ctx: &mut PdfContext, i: usize) {
     ctx.pdf.stream(content_id, &data).filter(Filter::FlateDecode);
 }
 
+/// Start a borderless link annotation.
+fn link_annotation<'a>(annotations: &'a mut Array, rect: Rect) -> Annotation<'a> {
+    let mut annotation = annotations.push().start::<Annotation>();
+    annotation.subtype(AnnotationType::Link).rect(rect);
+    annotation.border(0.0, 0.0, 0.0, None);
+    annotation
+}
+
 /// Write the page labels.
 #[tracing::instrument(skip_all)]
 pub(crate) fn write_page_labels(ctx: &mut PdfContext) -> Vec<(NonZeroUsize, Ref)> {
@@ -242,6 +277,48 @@ pub(crate) fn write_page_labels(ctx: &mut PdfContext) -> Vec<(NonZeroUsize, Ref)
     result
 }
 
+/// Write a named destination for each labelled element, so that other
+/// documents and viewers can link to it with `file.pdf#label`.
+///
+/// The destinations are sorted by name, as required for the name tree.
+#[tracing::instrument(skip_all)]
+pub(crate) fn write_named_destinations(ctx: &mut PdfContext) -> Vec<(Label, Ref)> {
+    let mut seen = HashSet::new();
+    let mut result = vec![];
+
+    for elem in ctx.introspector.all() {
+        let (Some(label), Some(loc)) = (elem.label(), elem.location()) else {
+            continue;
+        };
+
+        // Only the first element with a label gets the destination.
+        if !seen.insert(label) {
+            continue;
+        }
+
+        // Elements on pages that are not exported get no destination.
+        let pos = ctx.introspector.position(loc);
+        let Some(index) = ctx.page_indices.get(pos.page) else { continue };
+        let page = &ctx.pages[index];
+        let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
+
+        let id = ctx.alloc.bump();
+        ctx.pdf
+            .indirect(id)
+            .array()
+            .item(ctx.page_refs[index])
+            .item(Name(b"XYZ"))
+            .item(pos.point.x.to_f32())
+            .item((page.size.y - y).to_f32())
+            .item(Null);
+
+        result.push((label, id));
+    }
+
+    result.sort_by_key(|(label, _)| label.as_str());
+    result
+}
+
 /// Memoized version of [`deflate`] specialized for a page's content stream.
 #[comemo::memoize]
 fn deflate_content(content: &[u8]) -> Arc<Vec<u8>> {
@@ -364,7 +441,7 @@ impl PageContext<'_, '_> {
             .map(|stroke| {
                 let color = match &stroke.paint {
                     Paint::Solid(color) => *color,
-                    Paint::Gradient(_) => return 255,
+                    Paint::Gradient(_) | Paint::Spot(_) => return 255,
                 };
 
                 color.alpha().map_or(255, |v| (v * 255.0).round() as u8)
@@ -374,7 +451,7 @@ impl PageContext<'_, '_> {
             .map(|paint| {
                 let color = match paint {
                     Paint::Solid(color) => *color,
-                    Paint::Gradient(_) => return 255,
+                    Paint::Gradient(_) | Paint::Spot(_) => return 255,
                 };
 
                 color.alpha().map_or(255, |v| (v * 255.0).round() as u8)
@@ -517,6 +594,15 @@ fn write_frame(ctx: &mut PageContext, frame: &Frame) {
 fn write_group(ctx: &mut PageContext, pos: Point, group: &GroupItem) {
     let translation = Transform::translate(pos.x, pos.y);
 
+    // Only groups directly on the page can be shared as form XObjects.
+    let page = ctx.state.size;
+    let form = ctx
+        .saves
+        .is_empty()
+        .then(|| form_key(page, pos, group))
+        .flatten()
+        .filter(|key| ctx.parent.forms.contains_key(key));
+
     ctx.save_state();
 
     if group.frame.kind().is_hard() {
@@ -537,10 +623,116 @@ fn write_group(ctx: &mut PageContext, pos: Point, group: &GroupItem) {
         ctx.content.end_path();
     }
 
-    write_frame(ctx, &group.frame);
+    match form {
+        Some(key) => write_form(ctx, key, page, pos, &group.frame),
+        None => write_frame(ctx, &group.frame),
+    }
+
     ctx.restore_state();
 }
 
+/// Paint a repeated group's frame as a form XObject, encoding it on first use.
+fn write_form(ctx: &mut PageContext, key: u128, page: Size, pos: Point, frame: &Frame) {
+    let form = match ctx.parent.forms[&key] {
+        Some(form) => form,
+        None => {
+            let mut form_ctx = PageContext {
+                parent: ctx.parent,
+                page_ref: ctx.page_ref,
+                label: None,
+                uses_opacities: false,
+                content: Content::new(),
+                state: State::new(frame.size()),
+                saves: vec![],
+                bottom: 0.0,
+                links: vec![],
+            };
+
+            write_frame(&mut form_ctx, frame);
+            let uses_opacities = form_ctx.uses_opacities;
+            let data = deflate(&form_ctx.content.finish());
+
+            // The form is clipped to the part of the page it can cover.
+            let id = ctx.parent.alloc.bump();
+            let mut form_writer = ctx.parent.pdf.form_xobject(id, &data);
+            form_writer.filter(Filter::FlateDecode);
+            form_writer.bbox(Rect::new(
+                -pos.x.to_f32(),
+                -pos.y.to_f32(),
+                (page.x - pos.x).to_f32(),
+                (page.y - pos.y).to_f32(),
+            ));
+            form_writer.pair(Name(b"Resources"), ctx.parent.resources_ref);
+            form_writer.finish();
+
+            let form = Form { index: ctx.parent.form_refs.len(), uses_opacities };
+            ctx.parent.form_refs.push(id);
+            ctx.parent.forms.insert(key, Some(form));
+            form
+        }
+    };
+
+    ctx.uses_opacities |= form.uses_opacities;
+    let name = eco_format!("Fm{}", form.index);
+    ctx.content.x_object(Name(name.as_bytes()));
+}
+
+/// A group that is written once as a form XObject and reused across pages.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Form {
+    /// The index of the form's reference in the context's `form_refs`.
+    index: usize,
+    /// Whether the form uses opacities.
+    uses_opacities: bool,
+}
+
+/// Identify a group directly on a page with the given size that could be
+/// shared as a form XObject.
+///
+/// Returns `None` for groups that depend on where they end up: Links and page
+/// labels belong to a specific page and gradients are relative to the
+/// surrounding containers.
+fn form_key(page: Size, pos: Point, group: &GroupItem) -> Option<u128> {
+    (group.transform.is_identity() && is_shareable(&group.frame))
+        .then(|| hash128(&(page, pos, visual_hash(&group.frame))))
+}
+
+/// Whether a frame's contents can be written into a form XObject.
+fn is_shareable(frame: &Frame) -> bool {
+    let gradient = |paint: &Paint| matches!(paint, Paint::Gradient(_));
+    frame.items().all(|(_, item)| match item {
+        FrameItem::Group(group) => is_shareable(&group.frame),
+        FrameItem::Text(text) => !gradient(&text.fill),
+        FrameItem::Shape(shape, _) => {
+            !shape.fill.as_ref().is_some_and(gradient)
+                && !shape.stroke.as_ref().is_some_and(|stroke| gradient(&stroke.paint))
+        }
+        FrameItem::Image(..) => true,
+        FrameItem::Meta(meta, _) => {
+            !matches!(meta, Meta::Link(_) | Meta::PdfPageLabel(_))
+        }
+    })
+}
+
+/// Hash the visible contents of a frame, ignoring metadata like element
+/// locations, which differ between otherwise identical headers and footers.
+fn visual_hash(frame: &Frame) -> u128 {
+    let items: Vec<u128> = frame
+        .items()
+        .filter_map(|(pos, item)| match item {
+            FrameItem::Meta(..) => None,
+            FrameItem::Group(group) => Some(hash128(&(
+                pos,
+                group.transform,
+                &group.clip_path,
+                visual_hash(&group.frame),
+            ))),
+            item => Some(hash128(&(pos, item))),
+        })
+        .collect();
+    hash128(&(frame.size(), frame.kind(), items))
+}
+
 /// Encode a text run into the content stream.
 fn write_text(ctx: &mut PageContext, pos: Point, text: &TextItem) {
     let x = pos.x.to_f32();
@@ -696,7 +888,7 @@ fn write_image(ctx: &mut PageContext, x: f32, y: f32, image: &Image, size: Size)
         let mut image_span =
             ctx.content.begin_marked_content_with_properties(Name(b"Span"));
         let mut image_alt = image_span.properties();
-        image_alt.pair(Name(b"Alt"), pdf_writer::Str(alt.as_bytes()));
+        image_alt.pair(Name(b"Alt"), TextStr(alt));
         image_alt.finish();
         image_span.finish();
 
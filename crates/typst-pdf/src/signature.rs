@@ -0,0 +1,133 @@
+use pdf_writer::{Name, Primitive, Rect, Ref, TextStr};
+
+use crate::PdfContext;
+
+/// The number of bytes reserved for the signature.
+pub const SIGNATURE_SIZE: usize = 8192;
+
+/// The byte range before it is filled in. The large numbers reserve enough
+/// digits for any realistic file size.
+const BYTE_RANGE_PLACEHOLDER: &[u8] = b"[0 1000000000 1000000000 1000000000]";
+
+/// Write the signature field with an empty signature value.
+///
+/// Returns the byte offset at which the signature value is written, so that
+/// its byte range can be found again in the finished file.
+pub(crate) fn write_signature_field(ctx: &mut PdfContext, field: Ref) -> usize {
+    let value = ctx.alloc.bump();
+    let offset = ctx.pdf.as_bytes().len();
+    ctx.pdf
+        .indirect(value)
+        .dict()
+        .pair(Name(b"Type"), Name(b"Sig"))
+        .pair(Name(b"Filter"), Name(b"Adobe.PPKLite"))
+        .pair(Name(b"SubFilter"), Name(b"adbe.pkcs7.detached"))
+        .pair(Name(b"ByteRange"), Placeholder(BYTE_RANGE_PLACEHOLDER))
+        .pair(Name(b"Contents"), Contents);
+
+    // An invisible widget that is locked and printed.
+    let mut widget = ctx.pdf.indirect(field).dict();
+    widget.pair(Name(b"Type"), Name(b"Annot"));
+    widget.pair(Name(b"Subtype"), Name(b"Widget"));
+    widget.pair(Name(b"FT"), Name(b"Sig"));
+    widget.pair(Name(b"T"), TextStr("Signature"));
+    widget.pair(Name(b"V"), value);
+    widget.pair(Name(b"F"), 132);
+    widget.pair(Name(b"Rect"), Rect::new(0.0, 0.0, 0.0, 0.0));
+    widget.pair(Name(b"P"), ctx.page_refs[0]);
+    offset
+}
+
+/// Fill in the byte range of the signature value written at `offset`, which
+/// covers the whole file except for the signature's contents.
+///
+/// Only the signature value itself is searched, so that the same bytes in
+/// earlier parts of the file, like the document title, are left alone.
+pub(crate) fn fill_byte_range(buf: &mut [u8], offset: usize) {
+    let Some(range) = find(&buf[offset..], BYTE_RANGE_PLACEHOLDER) else { return };
+    let range = offset + range;
+    let Some(offset) = find(&buf[range..], b"/Contents <") else { return };
+
+    let start = range + offset + b"/Contents ".len();
+    let end = start + 2 * SIGNATURE_SIZE + 2;
+    let filled = format!("[0 {} {} {}", start, end, buf.len() - end);
+    let slot = &mut buf[range..range + BYTE_RANGE_PLACEHOLDER.len() - 1];
+    slot.fill(b' ');
+    slot[..filled.len()].copy_from_slice(filled.as_bytes());
+}
+
+/// Find the first occurrence of a byte sequence.
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+/// Raw bytes that are written as is.
+struct Placeholder(&'static [u8]);
+
+impl Primitive for Placeholder {
+    fn write(self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.0);
+    }
+}
+
+/// A hex string of zeros that reserves space for the signature.
+struct Contents;
+
+impl Primitive for Contents {
+    fn write(self, buf: &mut Vec<u8>) {
+        buf.push(b'<');
+        buf.resize(buf.len() + 2 * SIGNATURE_SIZE, b'0');
+        buf.push(b'>');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typst::doc::{Document, Frame};
+    use typst::geom::{Abs, Size};
+
+    use super::*;
+    use crate::PdfOptions;
+
+    /// Parse the filled-in byte range of the signature value.
+    fn byte_range(buf: &[u8]) -> [usize; 4] {
+        let sig = find(buf, b"/Type /Sig").unwrap();
+        let start = sig + find(&buf[sig..], b"/ByteRange [").unwrap();
+        let end = start + find(&buf[start..], b"]").unwrap();
+        let text = std::str::from_utf8(&buf[start + b"/ByteRange [".len()..end]);
+        let numbers: Vec<usize> =
+            text.unwrap().split_whitespace().map(|n| n.parse().unwrap()).collect();
+        numbers.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_byte_range_surrounds_contents() {
+        let placeholder = std::str::from_utf8(BYTE_RANGE_PLACEHOLDER).unwrap();
+        let document = Document {
+            pages: vec![Frame::soft(Size::splat(Abs::pt(100.0)))],
+            title: Some(placeholder.into()),
+            keywords: vec![placeholder.into()],
+            ..Document::default()
+        };
+
+        let options = PdfOptions {
+            signature_placeholder: true,
+            ..PdfOptions::default()
+        };
+        let buf = crate::pdf_with_options(&document, None, None, &options);
+
+        // The title and keywords are kept as they are.
+        assert!(find(&buf, BYTE_RANGE_PLACEHOLDER).is_some());
+
+        // The byte range covers everything except the hex string of the
+        // signature's contents.
+        let [first, first_len, second, second_len] = byte_range(&buf);
+        let contents = find(&buf, b"/Contents <").unwrap() + b"/Contents ".len();
+        assert_eq!(first, 0);
+        assert_eq!(first_len, contents);
+        assert_eq!(second, contents + 2 * SIGNATURE_SIZE + 2);
+        assert_eq!(second + second_len, buf.len());
+        assert_eq!(buf[contents], b'<');
+        assert_eq!(buf[second - 1], b'>');
+    }
+}
@@ -10,12 +10,14 @@ use resvg::tiny_skia::IntRect;
 use tiny_skia as sk;
 use ttf_parser::{GlyphId, OutlineBuilder};
 use typst::doc::{Frame, FrameItem, FrameKind, GroupItem, Meta, TextItem};
+use typst::eval::Regex;
 use typst::font::Font;
 use typst::geom::{
     self, Abs, Axes, Color, FixedStroke, Geometry, Gradient, LineCap, LineJoin, Paint,
     PathItem, Point, Ratio, Relative, Shape, Size, Transform,
 };
 use typst::image::{Image, ImageKind, RasterFormat};
+use typst::syntax::Span;
 use usvg::{NodeExt, TreeParsing};
 
 /// Export a frame into a raster image.
@@ -23,17 +25,80 @@ use usvg::{NodeExt, TreeParsing};
 /// This renders the frame at the given number of pixels per point and returns
 /// the resulting `tiny-skia` pixel buffer.
 pub fn render(frame: &Frame, pixel_per_pt: f32, fill: Color) -> sk::Pixmap {
-    let size = frame.size();
-    let pxw = (pixel_per_pt * size.x.to_f32()).round().max(1.0) as u32;
-    let pxh = (pixel_per_pt * size.y.to_f32()).round().max(1.0) as u32;
+    render_impl(frame, pixel_per_pt, fill, false)
+}
 
-    let mut canvas = sk::Pixmap::new(pxw, pxh).unwrap();
-    canvas.fill(to_sk_color(fill));
+/// Export a frame into a raster image, highlighting all matches of a pattern
+/// in its text.
+///
+/// Matches are searched for within individual runs of text and highlighted
+/// with a rectangle of the given color behind the text. Rotated or skewed text
+/// is highlighted with an axis-aligned rectangle.
+pub fn render_highlighted(
+    frame: &Frame,
+    pixel_per_pt: f32,
+    fill: Color,
+    pattern: &Regex,
+    highlight: Color,
+) -> sk::Pixmap {
+    let mut rects = vec![];
+    for (pos, text) in frame.text_runs() {
+        let metrics = text.font.metrics();
+        let top = pos.y - metrics.ascender.at(text.size);
+        let height = (metrics.ascender - metrics.descender).at(text.size);
+
+        for found in pattern.find_iter(&text.text).filter(|m| !m.is_empty()) {
+            // Find the horizontal extent of the glyphs that belong to the match.
+            let mut x = pos.x;
+            let mut extent: Option<(Abs, Abs)> = None;
+            for glyph in &text.glyphs {
+                let advance = glyph.x_advance.at(text.size);
+                let range = glyph.range();
+                if range.start < found.end() && found.start() < range.end {
+                    let start = extent.map_or(x, |(start, _)| start.min(x));
+                    let end = extent.map_or(x + advance, |(_, end)| end.max(x + advance));
+                    extent = Some((start, end));
+                }
+                x += advance;
+            }
 
-    let ts = sk::Transform::from_scale(pixel_per_pt, pixel_per_pt);
-    render_frame(&mut canvas, State::new(size, ts, pixel_per_pt), frame);
+            if let Some((start, end)) = extent {
+                let shape = Geometry::Rect(Size::new(end - start, height))
+                    .filled(highlight.into());
+                rects.push((
+                    Point::new(start, top),
+                    FrameItem::Shape(shape, Span::detached()),
+                ));
+            }
+        }
+    }
 
-    canvas
+    let mut frame = frame.clone();
+    frame.prepend_multiple(rects);
+    render(&frame, pixel_per_pt, fill)
+}
+
+/// Export frames into low-resolution thumbnails.
+///
+/// Each frame is scaled so that its longer side is `max_size` pixels long. This
+/// trades quality for speed: Glyphs are snapped to whole pixels so that each
+/// glyph is rasterized only once per size and images are downscaled with a
+/// cheaper filter.
+pub fn render_thumbnails(
+    frames: &[Frame],
+    max_size: u32,
+    fill: Color,
+) -> Vec<sk::Pixmap> {
+    frames
+        .iter()
+        .map(|frame| {
+            let size = frame.size();
+            let longest = size.x.max(size.y).to_f32();
+            let pixel_per_pt =
+                if longest > 0.0 { max_size as f32 / longest } else { 1.0 };
+            render_impl(frame, pixel_per_pt, fill, true)
+        })
+        .collect()
 }
 
 /// Export multiple frames into a single raster image.
@@ -77,6 +142,22 @@ pub fn render_merged(
     canvas
 }
 
+/// Render a frame, optionally trading quality for speed.
+fn render_impl(frame: &Frame, pixel_per_pt: f32, fill: Color, fast: bool) -> sk::Pixmap {
+    let size = frame.size();
+    let pxw = (pixel_per_pt * size.x.to_f32()).round().max(1.0) as u32;
+    let pxh = (pixel_per_pt * size.y.to_f32()).round().max(1.0) as u32;
+
+    let mut canvas = sk::Pixmap::new(pxw, pxh).unwrap();
+    canvas.fill(to_sk_color(fill));
+
+    let ts = sk::Transform::from_scale(pixel_per_pt, pixel_per_pt);
+    let state = State { fast, ..State::new(size, ts, pixel_per_pt) };
+    render_frame(&mut canvas, state, frame);
+
+    canvas
+}
+
 /// Additional metadata carried through the rendering process.
 #[derive(Clone, Copy, Default)]
 struct State<'a> {
@@ -90,6 +171,8 @@ struct State<'a> {
     pixel_per_pt: f32,
     /// The size of the first hard frame in the hierarchy.
     size: Size,
+    /// Whether to trade quality for speed, as for thumbnails.
+    fast: bool,
 }
 
 impl<'a> State<'a> {
@@ -421,26 +504,39 @@ fn render_outline_glyph(
         )))
     }
 
+    // In fast mode, snap the glyph to whole pixels, rasterize it at the origin,
+    // and move the bitmap into place. This way, its rasterization is reused
+    // at all other positions.
+    let (tx, ty, offset) = if state.fast {
+        (0.0, 0.0, (ts.tx.round() as i32, ts.ty.round() as i32))
+    } else {
+        (ts.tx, ts.ty, (0, 0))
+    };
+
     // Try to retrieve a prepared glyph or prepare it from scratch if it
     // doesn't exist, yet.
-    let bitmap =
-        rasterize(&text.font, id, ts.tx.to_bits(), ts.ty.to_bits(), ppem.to_bits())?;
+    let bitmap = rasterize(&text.font, id, tx.to_bits(), ty.to_bits(), ppem.to_bits())?;
     match &text.fill {
         Paint::Gradient(gradient) => {
             let sampler = GradientSampler::new(gradient, &state, Size::zero(), true);
-            write_bitmap(canvas, &bitmap, &state, sampler)?;
+            write_bitmap(canvas, &bitmap, offset, &state, sampler)?;
         }
         Paint::Solid(color) => {
-            write_bitmap(canvas, &bitmap, &state, *color)?;
+            write_bitmap(canvas, &bitmap, offset, &state, *color)?;
+        }
+        Paint::Spot(spot) => {
+            write_bitmap(canvas, &bitmap, offset, &state, spot.fallback())?;
         }
     }
 
     Some(())
 }
 
+/// Write a glyph bitmap into the canvas, moved by the given pixel offset.
 fn write_bitmap<S: PaintSampler>(
     canvas: &mut sk::Pixmap,
     bitmap: &Bitmap,
+    (dx, dy): (i32, i32),
     state: &State,
     sampler: S,
 ) -> Option<()> {
@@ -468,8 +564,8 @@ fn write_bitmap<S: PaintSampler>(
             }
         }
 
-        let left = bitmap.left;
-        let top = bitmap.top;
+        let left = bitmap.left + dx;
+        let top = bitmap.top + dy;
 
         canvas.draw_pixmap(
             left - 1,
@@ -486,9 +582,9 @@ fn write_bitmap<S: PaintSampler>(
         let mh = bitmap.height as i32;
 
         // Determine the pixel bounding box that we actually need to draw.
-        let left = bitmap.left;
+        let left = bitmap.left + dx;
         let right = left + mw;
-        let top = bitmap.top;
+        let top = bitmap.top + dy;
         let bottom = top + mh;
 
         // Blend the glyph bitmap with the existing pixels on the canvas.
@@ -688,7 +784,7 @@ fn render_image(
     let w = (scale_x * view_width.max(aspect * view_height)).ceil() as u32;
     let h = ((w as f32) / aspect).ceil() as u32;
 
-    let pixmap = scaled_texture(image, w, h)?;
+    let pixmap = scaled_texture(image, w, h, state.fast)?;
     let paint_scale_x = view_width / pixmap.width() as f32;
     let paint_scale_y = view_height / pixmap.height() as f32;
 
@@ -710,14 +806,19 @@ fn render_image(
 }
 
 /// Prepare a texture for an image at a scaled size.
+///
+/// In fast mode, a cheaper filter is used for resizing raster images.
 #[comemo::memoize]
-fn scaled_texture(image: &Image, w: u32, h: u32) -> Option<Arc<sk::Pixmap>> {
+fn scaled_texture(image: &Image, w: u32, h: u32, fast: bool) -> Option<Arc<sk::Pixmap>> {
     let mut pixmap = sk::Pixmap::new(w, h)?;
     match image.kind() {
         ImageKind::Raster(raster) => {
             let downscale = w < image.width();
-            let filter =
-                if downscale { FilterType::Lanczos3 } else { FilterType::CatmullRom };
+            let filter = match (fast, downscale) {
+                (true, _) => FilterType::Triangle,
+                (false, true) => FilterType::Lanczos3,
+                (false, false) => FilterType::CatmullRom,
+            };
             let buf = raster.dynamic().resize(w, h, filter);
             for ((_, _, src), dest) in buf.pixels().zip(pixmap.pixels_mut()) {
                 let Rgba([r, g, b, a]) = src;
@@ -854,6 +955,10 @@ fn to_sk_paint<'a>(
             sk_paint.set_color(to_sk_color(*color));
             sk_paint.anti_alias = true;
         }
+        Paint::Spot(spot) => {
+            sk_paint.set_color(to_sk_color(spot.fallback()));
+            sk_paint.anti_alias = true;
+        }
         Paint::Gradient(gradient) => {
             let relative = gradient.unwrap_relative(on_text);
             let container_size = match relative {
@@ -990,3 +1095,103 @@ fn alpha_mul(color: u32, scale: u32) -> u32 {
 fn offset_bounding_box(bbox: Size, stroke_width: Abs) -> Size {
     Size::new(bbox.x + stroke_width * 2.0, bbox.y + stroke_width * 2.0)
 }
+
+#[cfg(test)]
+mod tests {
+    use typst::doc::{Glyph, Lang};
+    use typst::eval::Bytes;
+
+    use super::*;
+
+    /// Shape a string naively, one glyph per character.
+    fn text(string: &str, size: Abs) -> TextItem {
+        let data = include_bytes!("../../../assets/fonts/IBMPlexSans-Regular.ttf");
+        let font = Font::new(Bytes::from_static(data), 0).unwrap();
+        let glyphs = string
+            .char_indices()
+            .map(|(i, c)| {
+                let id = font.ttf().glyph_index(c).unwrap().0;
+                Glyph {
+                    id,
+                    x_advance: font.advance(id).unwrap(),
+                    x_offset: geom::Em::zero(),
+                    range: i as u16..(i + c.len_utf8()) as u16,
+                    span: (Span::detached(), 0),
+                }
+            })
+            .collect();
+        TextItem {
+            font,
+            size,
+            fill: Color::BLACK.into(),
+            lang: Lang::ENGLISH,
+            text: string.into(),
+            glyphs,
+        }
+    }
+
+    #[test]
+    fn test_thumbnails_keep_aspect_ratio() {
+        let sizes = [(595.0, 842.0), (842.0, 595.0), (300.0, 300.0), (120.0, 40.0)];
+        let frames: Vec<_> = sizes
+            .iter()
+            .map(|&(w, h)| Frame::soft(Size::new(Abs::pt(w), Abs::pt(h))))
+            .collect();
+
+        let thumbnails = render_thumbnails(&frames, 128, Color::WHITE);
+        assert_eq!(thumbnails.len(), frames.len());
+        for (pixmap, &(w, h)) in thumbnails.iter().zip(&sizes) {
+            assert_eq!(pixmap.width().max(pixmap.height()), 128);
+            let ratio = pixmap.width() as f64 / pixmap.height() as f64;
+            let expected = w / h;
+            assert!((ratio - expected).abs() < expected / 64.0, "{ratio} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_highlighted_match() {
+        let size = Abs::pt(20.0);
+        let item = text("hello world", size);
+        let metrics = item.font.metrics();
+        let top = Abs::pt(40.0) - metrics.ascender.at(size);
+        let bottom = Abs::pt(40.0) - metrics.descender.at(size);
+        let advance = |range: std::ops::Range<usize>| -> Abs {
+            item.glyphs[range].iter().map(|g| g.x_advance.at(size)).sum()
+        };
+        let start = Abs::pt(10.0) + advance(0..6);
+        let end = start + advance(6..11);
+
+        let mut frame = Frame::soft(Size::new(Abs::pt(200.0), Abs::pt(60.0)));
+        frame.push(Point::new(Abs::pt(10.0), Abs::pt(40.0)), FrameItem::Text(item));
+
+        let red = Color::from_u8(255, 0, 0, 255);
+        let pattern = Regex::new("wor?ld").unwrap();
+        let plain = render(&frame, 2.0, Color::WHITE);
+        let highlighted = render_highlighted(&frame, 2.0, Color::WHITE, &pattern, red);
+
+        // Only the pixels of the match change and those that were blank before
+        // are now red. A margin of one pixel allows for antialiasing.
+        let px = |abs: Abs| abs.to_f32() * 2.0;
+        let (x0, x1, y0, y1) = (px(start), px(end), px(top), px(bottom));
+        let mut reds = 0;
+        for y in 0..plain.height() {
+            for x in 0..plain.width() {
+                let before = plain.pixel(x, y).unwrap();
+                let after = highlighted.pixel(x, y).unwrap();
+                let (x, y) = (x as f32, y as f32);
+                let near =
+                    x + 1.0 >= x0 && x <= x1 + 1.0 && y + 1.0 >= y0 && y <= y1 + 1.0;
+                let inside = x > x0 + 1.0 && x + 1.0 < x1 && y > y0 + 1.0 && y + 1.0 < y1;
+                let blank = before.red() == 255 && before.green() == 255;
+                if !near {
+                    assert_eq!(before, after, "pixel at {x}, {y} changed");
+                } else if inside && blank {
+                    assert_eq!((after.red(), after.green(), after.blue()), (255, 0, 0));
+                    reds += 1;
+                }
+            }
+        }
+
+        assert!(reds > 0);
+    }
+}
@@ -529,6 +529,9 @@ impl SVGRenderer {
     fn write_fill(&mut self, fill: &Paint, size: Size, ts: Transform) {
         match fill {
             Paint::Solid(color) => self.xml.write_attribute("fill", &color.encode()),
+            Paint::Spot(spot) => {
+                self.xml.write_attribute("fill", &spot.fallback().encode())
+            }
             Paint::Gradient(gradient) => {
                 let id = self.push_gradient(gradient, size, ts);
                 self.xml.write_attribute_fmt("fill", format_args!("url(#{id})"));
@@ -571,6 +574,9 @@ impl SVGRenderer {
     ) {
         match &stroke.paint {
             Paint::Solid(color) => self.xml.write_attribute("stroke", &color.encode()),
+            Paint::Spot(spot) => {
+                self.xml.write_attribute("stroke", &spot.fallback().encode())
+            }
             Paint::Gradient(gradient) => {
                 let id = self.push_gradient(gradient, size, fill_transform);
                 self.xml.write_attribute_fmt("stroke", format_args!("url(#{id})"));
@@ -618,6 +624,11 @@ impl SVGRenderer {
         self.xml.write_attribute("width", &size.x.to_pt());
         self.xml.write_attribute("height", &size.y.to_pt());
         self.xml.write_attribute("preserveAspectRatio", "none");
+        if let Some(alt) = image.alt() {
+            self.xml.start_element("title");
+            self.xml.write_text(alt);
+            self.xml.end_element();
+        }
         self.xml.end_element();
     }
 
@@ -1080,6 +1091,7 @@ fn convert_image_to_base64_url(image: &Image) -> EcoString {
             RasterFormat::Png => "png",
             RasterFormat::Jpg => "jpeg",
             RasterFormat::Gif => "gif",
+            RasterFormat::Webp => "webp",
         },
         ImageFormat::Vector(f) => match f {
             VectorFormat::Svg => "svg+xml",
@@ -1319,3 +1331,32 @@ impl ColorEncode for Color {
 fn correct_pattern_pos(x: f32) -> f32 {
     (x + 0.5) / 2.0
 }
+
+#[cfg(test)]
+mod tests {
+    use typst::eval::Bytes;
+    use typst::syntax::Span;
+
+    use super::*;
+
+    #[test]
+    fn test_image_alt_text() {
+        let data = Bytes::from_static(
+            b"<svg xmlns='http://www.w3.org/2000/svg' width='10' height='10'/>",
+        );
+        let format = ImageFormat::Vector(VectorFormat::Svg);
+        let alt = Some("A square & more".into());
+        let image = Image::new(data.clone(), format, alt).unwrap();
+        let plain = Image::new(data, format, None).unwrap();
+
+        let size = Size::splat(Abs::pt(10.0));
+        let mut frame = Frame::soft(Size::splat(Abs::pt(100.0)));
+        frame.push(Point::zero(), FrameItem::Image(image, size, Span::detached()));
+        frame.push(Point::zero(), FrameItem::Image(plain, size, Span::detached()));
+
+        let svg = svg(&frame);
+        assert_eq!(svg.matches("<image ").count(), 2);
+        assert_eq!(svg.matches("<title>").count(), 1);
+        assert!(svg.contains("<title>A square &amp; more</title>"));
+    }
+}
@@ -1325,8 +1325,8 @@ impl UnOp {
     /// The precedence of this operator.
     pub fn precedence(self) -> usize {
         match self {
-            Self::Pos | Self::Neg => 7,
-            Self::Not => 4,
+            Self::Pos | Self::Neg => 8,
+            Self::Not => 5,
         }
     }
 
@@ -1414,6 +1414,8 @@ pub enum BinOp {
     MulAssign,
     /// The divide-assign operator: `/=`.
     DivAssign,
+    /// The pipe operator: `>>`.
+    Pipe,
 }
 
 impl BinOp {
@@ -1438,6 +1440,7 @@ impl BinOp {
             SyntaxKind::HyphEq => Self::SubAssign,
             SyntaxKind::StarEq => Self::MulAssign,
             SyntaxKind::SlashEq => Self::DivAssign,
+            SyntaxKind::GtGt => Self::Pipe,
             _ => return Option::None,
         })
     }
@@ -1445,20 +1448,21 @@ impl BinOp {
     /// The precedence of this operator.
     pub fn precedence(self) -> usize {
         match self {
-            Self::Mul => 6,
-            Self::Div => 6,
-            Self::Add => 5,
-            Self::Sub => 5,
-            Self::Eq => 4,
-            Self::Neq => 4,
-            Self::Lt => 4,
-            Self::Leq => 4,
-            Self::Gt => 4,
-            Self::Geq => 4,
-            Self::In => 4,
-            Self::NotIn => 4,
-            Self::And => 3,
-            Self::Or => 2,
+            Self::Mul => 7,
+            Self::Div => 7,
+            Self::Add => 6,
+            Self::Sub => 6,
+            Self::Eq => 5,
+            Self::Neq => 5,
+            Self::Lt => 5,
+            Self::Leq => 5,
+            Self::Gt => 5,
+            Self::Geq => 5,
+            Self::In => 5,
+            Self::NotIn => 5,
+            Self::And => 4,
+            Self::Or => 3,
+            Self::Pipe => 2,
             Self::Assign => 1,
             Self::AddAssign => 1,
             Self::SubAssign => 1,
@@ -1489,6 +1493,7 @@ impl BinOp {
             Self::SubAssign => Assoc::Right,
             Self::MulAssign => Assoc::Right,
             Self::DivAssign => Assoc::Right,
+            Self::Pipe => Assoc::Left,
         }
     }
 
@@ -1514,6 +1519,7 @@ impl BinOp {
             Self::SubAssign => "-=",
             Self::MulAssign => "*=",
             Self::DivAssign => "/=",
+            Self::Pipe => ">>",
         }
     }
 }
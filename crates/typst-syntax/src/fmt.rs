@@ -0,0 +1,206 @@
+use crate::ast::BinOp;
+use crate::{SyntaxKind, SyntaxNode};
+
+/// Format a syntax tree into canonical source code.
+///
+/// The formatter only touches whitespace, so the result always parses into
+/// the same tree, modulo spaces. Comments, raw blocks, and strings are kept
+/// exactly as they are. In particular, the formatter
+/// - removes trailing whitespace at the end of lines,
+/// - puts a single space after heading markers,
+/// - puts a single space after commas and colons in argument lists, arrays,
+///   dictionaries, parameter lists, and imports and removes spaces before
+///   them,
+/// - puts single spaces between the parts of binary operations, closures,
+///   and statements like `let`, `set`, and `show`, and
+/// - inserts missing spaces around binary operators, `=`, and `=>`.
+///
+/// Spaces in math and line breaks are kept as they are. A tree with syntax
+/// errors is returned unchanged.
+pub fn format(root: &SyntaxNode) -> String {
+    if root.erroneous() {
+        return root.clone().into_text().into();
+    }
+
+    let mut formatter = Formatter { out: String::new(), math: false };
+    formatter.node(root);
+    formatter.out
+}
+
+/// Writes formatted nodes into a string.
+struct Formatter {
+    /// The formatted output.
+    out: String,
+    /// Whether we are in math, where we leave spaces alone.
+    math: bool,
+}
+
+impl Formatter {
+    /// Format a node and its children.
+    fn node(&mut self, node: &SyntaxNode) {
+        if node.kind() == SyntaxKind::LineComment {
+            self.out.push_str(node.text().trim_end_matches([' ', '\t']));
+            return;
+        } else if node.children().len() == 0 {
+            self.out.push_str(node.text());
+            return;
+        }
+
+        let prev_math = self.math;
+        self.math |= node.kind() == SyntaxKind::Equation;
+
+        let children: Vec<_> = node.children().collect();
+        for (i, child) in children.iter().enumerate() {
+            let prev = i.checked_sub(1).map(|j| children[j].kind());
+            let next = children.get(i + 1).map(|child| child.kind());
+            match child.kind() {
+                SyntaxKind::Space | SyntaxKind::Parbreak => {
+                    self.space(node.kind(), child.text(), prev, next);
+                }
+                SyntaxKind::Comma | SyntaxKind::Colon => {
+                    self.node(child);
+                    if !self.math
+                        && is_list(node.kind())
+                        && !matches!(
+                            next,
+                            None | Some(SyntaxKind::Space | SyntaxKind::RightParen)
+                        )
+                    {
+                        self.out.push(' ');
+                    }
+                }
+                kind if !self.math && is_operator(node.kind(), kind) => {
+                    if !matches!(prev, None | Some(SyntaxKind::Space)) {
+                        self.out.push(' ');
+                    }
+                    self.node(child);
+                    if !matches!(next, None | Some(SyntaxKind::Space)) {
+                        self.out.push(' ');
+                    }
+                }
+                _ => self.node(child),
+            }
+        }
+
+        self.math = prev_math;
+    }
+
+    /// Format whitespace between the `prev` and `next` siblings.
+    fn space(
+        &mut self,
+        parent: SyntaxKind,
+        text: &str,
+        prev: Option<SyntaxKind>,
+        next: Option<SyntaxKind>,
+    ) {
+        if text.contains('\n') {
+            // Keep the line breaks and the indentation of the last line, but
+            // drop the whitespace at the ends of the lines.
+            let (lines, last) = text.rsplit_once('\n').unwrap();
+            for line in lines.split('\n') {
+                self.out.push_str(line.trim_end_matches([' ', '\t']));
+                self.out.push('\n');
+            }
+            self.out.push_str(last);
+            return;
+        }
+
+        if self.math {
+            self.out.push_str(text);
+            return;
+        }
+
+        let normalized = match parent {
+            SyntaxKind::Heading if prev == Some(SyntaxKind::HeadingMarker) => " ",
+            _ if is_list(parent) => match (prev, next) {
+                (Some(SyntaxKind::LeftParen), _) => "",
+                (_, Some(SyntaxKind::RightParen)) => "",
+                (_, Some(SyntaxKind::Comma | SyntaxKind::Colon)) => "",
+                (Some(SyntaxKind::Comma | SyntaxKind::Colon), _) => " ",
+                _ => text,
+            },
+            _ if is_statement(parent) => match next {
+                Some(SyntaxKind::Colon) => "",
+                _ => " ",
+            },
+            _ => text,
+        };
+
+        self.out.push_str(normalized);
+    }
+}
+
+/// Whether nodes of this kind contain comma-separated items.
+fn is_list(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Args
+            | SyntaxKind::Array
+            | SyntaxKind::Dict
+            | SyntaxKind::Params
+            | SyntaxKind::Destructuring
+            | SyntaxKind::ImportItems
+            | SyntaxKind::Named
+            | SyntaxKind::Keyed
+    )
+}
+
+/// Whether nodes of this kind separate their parts by single spaces.
+fn is_statement(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Binary
+            | SyntaxKind::Closure
+            | SyntaxKind::LetBinding
+            | SyntaxKind::SetRule
+            | SyntaxKind::ShowRule
+            | SyntaxKind::Conditional
+            | SyntaxKind::WhileLoop
+            | SyntaxKind::ForLoop
+            | SyntaxKind::ModuleImport
+            | SyntaxKind::ModuleInclude
+            | SyntaxKind::FuncReturn
+    )
+}
+
+/// Whether a child of this kind is an operator that should be surrounded by
+/// spaces in a parent of the given kind.
+fn is_operator(parent: SyntaxKind, kind: SyntaxKind) -> bool {
+    match parent {
+        SyntaxKind::Binary => BinOp::from_kind(kind).is_some() || kind == SyntaxKind::Not,
+        SyntaxKind::LetBinding | SyntaxKind::DestructAssignment => kind == SyntaxKind::Eq,
+        SyntaxKind::Closure => matches!(kind, SyntaxKind::Eq | SyntaxKind::Arrow),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn test(text: &str, goal: &str) {
+        let formatted = format(&crate::parse(text));
+        assert_eq!(formatted, goal);
+        assert_eq!(format(&crate::parse(&formatted)), goal);
+    }
+
+    #[test]
+    fn test_format() {
+        test("=   Hello  \nWorld   \n\n  Next", "= Hello\nWorld\n\n  Next");
+        test("#f( a ,b:1 , c :  2 )", "#f(a, b: 1, c: 2)");
+        test("#let  x  =  (1,2)", "#let x = (1, 2)");
+        test("#let f(x,y:none)  =  x+  y", "#let f(x, y: none) = x + y");
+        test("#let x=1*-2", "#let x = 1 * -2");
+        test("#let f(x)=x", "#let f(x) = x");
+        test("#{(a,b)=(1,2); x+=a not in b}", "#{(a, b) = (1, 2); x += a not in b}");
+        test("#map(x=>x<=2)", "#map(x => x <= 2)");
+        test("#import  \"a.typ\" :  a,b", "#import \"a.typ\": a, b");
+        test("#show  heading :  it => it", "#show heading: it => it");
+        test("#if  x {a}  else  {b}", "#if x {a} else {b}");
+        test("#f(\n  a,\n  b,\n)", "#f(\n  a,\n  b,\n)");
+        test("// A  comment  \n`raw  `", "// A  comment\n`raw  `");
+        test("$f(a,b)  +  c$", "$f(a,b)  +  c$");
+        test("#f(a,", "#f(a,");
+    }
+}
@@ -214,6 +214,7 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::LtEq => Some(Tag::Operator),
         SyntaxKind::Gt => Some(Tag::Operator),
         SyntaxKind::GtEq => Some(Tag::Operator),
+        SyntaxKind::GtGt => Some(Tag::Operator),
         SyntaxKind::PlusEq => Some(Tag::Operator),
         SyntaxKind::HyphEq => Some(Tag::Operator),
         SyntaxKind::StarEq => Some(Tag::Operator),
@@ -128,6 +128,8 @@ pub enum SyntaxKind {
     Gt,
     /// The greater-than or equal operator: `>=`.
     GtEq,
+    /// The pipe operator: `>>`.
+    GtGt,
     /// The add-assign operator: `+=`.
     PlusEq,
     /// The subtract-assign operator: `-=`.
@@ -411,6 +413,7 @@ impl SyntaxKind {
             Self::LtEq => "less-than or equal operator",
             Self::Gt => "greater-than operator",
             Self::GtEq => "greater-than or equal operator",
+            Self::GtGt => "pipe operator",
             Self::PlusEq => "add-assign operator",
             Self::HyphEq => "subtract-assign operator",
             Self::StarEq => "multiply-assign operator",
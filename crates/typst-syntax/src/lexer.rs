@@ -81,6 +81,15 @@ impl Lexer<'_> {
         self.error = Some(message.into());
         SyntaxKind::Error
     }
+
+    /// Move to the next match of a vectorized byte search in the rest of the
+    /// text or to the end if there is none. The needles must be ASCII so that
+    /// we end up on a char boundary.
+    fn skip_until(&mut self, find: impl FnOnce(&[u8]) -> Option<usize>) {
+        let rest = self.s.after();
+        let offset = find(rest.as_bytes()).unwrap_or(rest.len());
+        self.s.jump(self.s.cursor() + offset);
+    }
 }
 
 /// Shared.
@@ -132,7 +141,14 @@ impl Lexer<'_> {
         let mut depth = 1;
 
         // Find the first `*/` that does not correspond to a nested `/*`.
-        while let Some(c) = self.s.eat() {
+        loop {
+            // Only stars and slashes can change the state, so we can skip
+            // everything else in one go.
+            if !matches!(state, '*' | '/') {
+                self.skip_until(|s| memchr::memchr2(b'*', b'/', s));
+            }
+
+            let Some(c) = self.s.eat() else { break };
             state = match (state, c) {
                 ('*', '/') => {
                     depth -= 1;
@@ -239,6 +255,10 @@ impl Lexer<'_> {
 
         let mut found = 0;
         while found < backticks {
+            if found == 0 {
+                self.skip_until(|s| memchr::memchr(b'`', s));
+            }
+
             match self.s.eat() {
                 Some('`') => found += 1,
                 Some(_) => found = 0,
@@ -462,6 +482,7 @@ impl Lexer<'_> {
             '!' if self.s.eat_if('=') => SyntaxKind::ExclEq,
             '<' if self.s.eat_if('=') => SyntaxKind::LtEq,
             '>' if self.s.eat_if('=') => SyntaxKind::GtEq,
+            '>' if self.s.eat_if('>') => SyntaxKind::GtGt,
             '+' if self.s.eat_if('=') => SyntaxKind::PlusEq,
             '-' | '\u{2212}' if self.s.eat_if('=') => SyntaxKind::HyphEq,
             '*' if self.s.eat_if('=') => SyntaxKind::StarEq,
@@ -589,12 +610,13 @@ impl Lexer<'_> {
     }
 
     fn string(&mut self) -> SyntaxKind {
-        let mut escaped = false;
-        self.s.eat_until(|c| {
-            let stop = c == '"' && !escaped;
-            escaped = c == '\\' && !escaped;
-            stop
-        });
+        loop {
+            self.skip_until(|s| memchr::memchr2(b'"', b'\\', s));
+            if !self.s.eat_if('\\') {
+                break;
+            }
+            self.s.eat();
+        }
 
         if !self.s.eat_if('"') {
             return self.error("unclosed string");
@@ -3,6 +3,7 @@
 pub mod ast;
 
 mod file;
+mod fmt;
 mod highlight;
 mod kind;
 mod lexer;
@@ -13,6 +14,7 @@ mod source;
 mod span;
 
 pub use self::file::{FileId, PackageSpec, PackageVersion, VirtualPath};
+pub use self::fmt::format;
 pub use self::highlight::{highlight, highlight_html, Tag};
 pub use self::kind::SyntaxKind;
 pub use self::lexer::{
@@ -25,3 +27,19 @@ pub use self::span::{Span, Spanned};
 
 use self::lexer::{LexMode, Lexer};
 use self::parser::{reparse_block, reparse_markup};
+
+/// Split markup into tokens without parsing it and return their kinds.
+///
+/// Since switching into code and math is driven by the parser, everything is
+/// lexed as markup. This is only exposed for benchmarking.
+#[doc(hidden)]
+pub fn tokenize(text: &str) -> Vec<SyntaxKind> {
+    let mut lexer = Lexer::new(text, LexMode::Markup);
+    let mut kinds = vec![];
+    loop {
+        match lexer.next() {
+            SyntaxKind::Eof => return kinds,
+            kind => kinds.push(kind),
+        }
+    }
+}
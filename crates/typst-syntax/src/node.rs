@@ -3,9 +3,11 @@ use std::ops::{Deref, Range};
 use std::rc::Rc;
 use std::sync::Arc;
 
-use ecow::{eco_vec, EcoString, EcoVec};
+use ecow::{eco_format, eco_vec, EcoString, EcoVec};
+use unicode_math_class::MathClass;
 
 use crate::ast::AstNode;
+use crate::parser::math_class;
 use crate::{FileId, Span, SyntaxKind};
 
 /// A node in the untyped syntax tree.
@@ -149,6 +151,66 @@ impl SyntaxNode {
         }
     }
 
+    /// Constructs in this node and its descendants which the parser accepted
+    /// by guessing what was meant, like a math delimiter without a closing
+    /// counterpart. They are no errors, but strict compilation rejects them.
+    pub fn heals(&self) -> Vec<SyntaxError> {
+        let mut heals = vec![];
+        self.collect_heals(&mut heals);
+        heals
+    }
+
+    /// Add the heals of this node and its descendants to `heals`.
+    fn collect_heals(&self, heals: &mut Vec<SyntaxError>) {
+        let heal = |node: &Self, message: EcoString, hint: &str| SyntaxError {
+            span: node.span(),
+            message,
+            hints: eco_vec![hint.into()],
+            code: None,
+        };
+
+        match self.kind() {
+            // The parser falls back to a plain math node if an opening
+            // delimiter is never closed.
+            SyntaxKind::Math => {
+                if let Some(first) = self.children().next().filter(|first| {
+                    matches!(first.kind(), SyntaxKind::Text | SyntaxKind::Shorthand)
+                        && math_class(first.text()) == Some(MathClass::Opening)
+                }) {
+                    heals.push(heal(
+                        first,
+                        eco_format!("unclosed delimiter `{}`", first.text()),
+                        "add a closing delimiter or escape this one with a backslash",
+                    ));
+                }
+            }
+            // Smart quotes are paired up during layout, and a double quote
+            // that is still open at the end of a paragraph is left as is.
+            SyntaxKind::Markup => {
+                let mut open = None;
+                for child in self.children() {
+                    match child.kind() {
+                        SyntaxKind::SmartQuote if child.text() == "\"" => {
+                            open = if open.is_some() { None } else { Some(child) };
+                        }
+                        SyntaxKind::Parbreak => heals.extend(open.take().map(|quote| {
+                            heal(quote, "unclosed quote".into(), QUOTE_HINT)
+                        })),
+                        _ => {}
+                    }
+                }
+                heals.extend(
+                    open.map(|quote| heal(quote, "unclosed quote".into(), QUOTE_HINT)),
+                );
+            }
+            _ => {}
+        }
+
+        for child in self.children() {
+            child.collect_heals(heals);
+        }
+    }
+
     /// Add a user-presentable hint if this is an error node.
     pub fn hint(&mut self, hint: impl Into<EcoString>) {
         if let Repr::Error(node) = &mut self.0 {
@@ -156,6 +218,13 @@ impl SyntaxNode {
         }
     }
 
+    /// Set a stable error code if this is an error node.
+    pub(super) fn code(&mut self, code: &'static str) {
+        if let Repr::Error(node) = &mut self.0 {
+            Arc::make_mut(node).error.code = Some(code);
+        }
+    }
+
     /// Set a synthetic span for the node and all its descendants.
     pub fn synthesize(&mut self, span: Span) {
         match &mut self.0 {
@@ -617,6 +686,7 @@ impl ErrorNode {
                 span: Span::detached(),
                 message: message.into(),
                 hints: eco_vec![],
+                code: None,
             },
         }
     }
@@ -643,6 +713,9 @@ impl Debug for ErrorNode {
     }
 }
 
+/// The hint for a double quote that is never closed.
+const QUOTE_HINT: &str = "add a closing quote or escape this one with a backslash";
+
 /// A syntactical error.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct SyntaxError {
@@ -653,12 +726,21 @@ pub struct SyntaxError {
     /// Additional hints to the user, indicating how this error could be avoided
     /// or worked around.
     pub hints: EcoVec<EcoString>,
+    /// A stable code identifying the kind of error, if any.
+    ///
+    /// - `E0001`: An unexpected token.
+    /// - `E0002`: A missing token or expression.
+    /// - `E0003`: An unclosed delimiter.
+    /// - `E0004`: A malformed token, like an unclosed string.
+    pub code: Option<&'static str>,
 }
 
 impl SyntaxError {
     /// Whether the two errors are the same apart from spans.
     fn spanless_eq(&self, other: &Self) -> bool {
-        self.message == other.message && self.hints == other.hints
+        self.message == other.message
+            && self.hints == other.hints
+            && self.code == other.code
     }
 }
 
@@ -987,4 +1069,21 @@ mod tests {
         assert_eq!(leaf.text(), " ");
         assert_eq!(next.text(), "10");
     }
+
+    #[test]
+    fn test_error_codes() {
+        #[track_caller]
+        fn test(text: &str, message: &str, code: &str) {
+            let errors = Source::detached(text).root().errors();
+            assert_eq!(errors.len(), 1, "{errors:?}");
+            assert_eq!(errors[0].message, message);
+            assert_eq!(errors[0].code, Some(code));
+        }
+
+        test("#{ , }", "unexpected comma", "E0001");
+        test("#let = 1", "expected identifier", "E0002");
+        test("#let if = 1", "expected identifier, found keyword `if`", "E0002");
+        test("#(1, 2", "unclosed delimiter", "E0003");
+        test("#\"abc", "unclosed string", "E0004");
+    }
 }
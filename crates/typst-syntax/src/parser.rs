@@ -443,7 +443,7 @@ fn math_unparen(p: &mut Parser, m: Marker) {
     node.convert_to_kind(SyntaxKind::Math);
 }
 
-fn math_class(text: &str) -> Option<MathClass> {
+pub(super) fn math_class(text: &str) -> Option<MathClass> {
     match text {
         "[|" => return Some(MathClass::Opening),
         "|]" => return Some(MathClass::Closing),
@@ -1648,7 +1648,9 @@ impl<'s> Parser<'s> {
         let text = self.current_text();
         if self.at(SyntaxKind::Error) {
             let message = self.lexer.take_error().unwrap();
-            self.nodes.push(SyntaxNode::error(message, text));
+            let mut error = SyntaxNode::error(message, text);
+            error.code("E0004");
+            self.nodes.push(error);
         } else {
             self.nodes.push(SyntaxNode::leaf(self.current, text));
         }
@@ -1711,14 +1713,15 @@ impl<'s> Parser<'s> {
     /// thing was `found` and consume the next token.
     fn expected_found(&mut self, thing: &str, found: &str) {
         self.trim_errors();
-        self.convert_to_error(eco_format!("expected {thing}, found {found}"));
+        self.convert_to_error("E0002", eco_format!("expected {thing}, found {found}"));
     }
 
     /// Produce an error that the given `thing` was expected at the position
     /// of the marker `m`.
     fn expected_at(&mut self, m: Marker, thing: &str) {
         let message = eco_format!("expected {}", thing);
-        let error = SyntaxNode::error(message, "");
+        let mut error = SyntaxNode::error(message, "");
+        error.code("E0002");
         self.nodes.insert(m.0, error);
     }
 
@@ -1727,6 +1730,7 @@ impl<'s> Parser<'s> {
     fn expect_closing_delimiter(&mut self, open: Marker, kind: SyntaxKind) {
         if !self.eat_if(kind) {
             self.nodes[open.0].convert_to_error("unclosed delimiter");
+            self.nodes[open.0].code("E0003");
         }
     }
 
@@ -1734,17 +1738,18 @@ impl<'s> Parser<'s> {
     /// unexpected.
     fn unexpected(&mut self) {
         self.trim_errors();
-        self.convert_to_error(eco_format!("unexpected {}", self.current.name()));
+        self.convert_to_error("E0001", eco_format!("unexpected {}", self.current.name()));
     }
 
-    /// Consume the next token and turn it into an error.
-    fn convert_to_error(&mut self, message: EcoString) {
+    /// Consume the next token and turn it into an error with the given code.
+    fn convert_to_error(&mut self, code: &'static str, message: EcoString) {
         let kind = self.current;
         let offset = self.nodes.len();
         self.eat();
         self.balanced &= !kind.is_grouping();
         if !kind.is_error() {
             self.nodes[offset].convert_to_error(message);
+            self.nodes[offset].code(code);
         }
     }
 
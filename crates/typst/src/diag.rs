@@ -95,6 +95,19 @@ pub struct SourceDiagnostic {
     /// Additional hints to the user, indicating how this problem could be avoided
     /// or worked around.
     pub hints: EcoVec<EcoString>,
+    /// Other places in the source code that are relevant to the problem, each
+    /// with a short note.
+    pub related: EcoVec<Spanned<EcoString>>,
+    /// A stable code identifying the kind of problem, if any.
+    ///
+    /// Syntax errors use the codes of [`SyntaxError::code`]. Beyond these:
+    ///
+    /// - `E0101`: An unknown variable.
+    /// - `E0102`: A missing or unexpected argument.
+    /// - `E0103`: An operator that is not applicable to its operands.
+    /// - `E0201`: A value outside of a function's domain.
+    /// - `E0202`: A division by zero.
+    pub code: Option<&'static str>,
 }
 
 /// The severity of a [`SourceDiagnostic`].
@@ -115,6 +128,8 @@ impl SourceDiagnostic {
             trace: eco_vec![],
             message: message.into(),
             hints: eco_vec![],
+            related: eco_vec![],
+            code: None,
         }
     }
 
@@ -126,6 +141,8 @@ impl SourceDiagnostic {
             trace: eco_vec![],
             message: message.into(),
             hints: eco_vec![],
+            related: eco_vec![],
+            code: None,
         }
     }
 
@@ -145,6 +162,18 @@ impl SourceDiagnostic {
         self.hints.extend(hints);
         self
     }
+
+    /// Points to another relevant place in the source code.
+    pub fn with_related(mut self, span: Span, note: impl Into<EcoString>) -> Self {
+        self.related.push(Spanned::new(note.into(), span));
+        self
+    }
+
+    /// Sets the stable code of the diagnostic.
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
 }
 
 impl From<SyntaxError> for SourceDiagnostic {
@@ -155,6 +184,8 @@ impl From<SyntaxError> for SourceDiagnostic {
             message: error.message,
             trace: eco_vec![],
             hints: error.hints,
+            related: eco_vec![],
+            code: error.code,
         }
     }
 }
@@ -268,6 +299,12 @@ impl From<EcoString> for HintedString {
     }
 }
 
+impl From<&str> for HintedString {
+    fn from(value: &str) -> Self {
+        Self { message: value.into(), hints: vec![] }
+    }
+}
+
 impl<T> At<T> for Result<T, HintedString> {
     fn at(self, span: Span) -> SourceResult<T> {
         self.map_err(|diags| {
@@ -303,6 +340,23 @@ impl<T> Hint<T> for HintedStrResult<T> {
     }
 }
 
+/// Attach a stable code to the diagnostics of a [`SourceResult`].
+pub trait Code<T> {
+    /// Set the code of all diagnostics that don't have one yet.
+    fn code(self, code: &'static str) -> SourceResult<T>;
+}
+
+impl<T> Code<T> for SourceResult<T> {
+    fn code(self, code: &'static str) -> SourceResult<T> {
+        self.map_err(|mut diags| {
+            for diag in diags.make_mut() {
+                diag.code.get_or_insert(code);
+            }
+            diags
+        })
+    }
+}
+
 /// A result type with a file-related error.
 pub type FileResult<T> = Result<T, FileError>;
 
@@ -462,3 +516,29 @@ pub fn format_xml_like_error(format: &str, error: roxmltree::Error) -> EcoString
         err => eco_format!("failed to parse {format} ({err})"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::Source;
+
+    #[test]
+    fn test_code_keeps_existing_codes() {
+        let result: SourceResult<()> = Err(eco_vec![
+            SourceDiagnostic::error(Span::detached(), "cannot add integer and string"),
+            SourceDiagnostic::error(Span::detached(), "divisor must not be zero")
+                .with_code("E0202"),
+        ]);
+        let errors = result.code("E0103").unwrap_err();
+        let codes: Vec<_> = errors.iter().map(|error| error.code).collect();
+        assert_eq!(codes, [Some("E0103"), Some("E0202")]);
+    }
+
+    #[test]
+    fn test_syntax_error_code() {
+        let source = Source::detached("#(1, 2");
+        let error = SourceDiagnostic::from(source.root().errors().remove(0));
+        assert_eq!(error.message, "unclosed delimiter");
+        assert_eq!(error.code, Some("E0003"));
+    }
+}
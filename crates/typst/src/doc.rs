@@ -1,12 +1,14 @@
 //! Finished documents.
 
 use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
 use std::ops::Range;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use ecow::{eco_format, EcoString};
+use siphasher::sip128::{Hasher128, SipHasher13};
 
 use crate::eval::{cast, dict, ty, Datetime, Dict, Repr, Smart, Value};
 use crate::font::Font;
@@ -33,6 +35,63 @@ pub struct Document {
     pub date: Smart<Option<Datetime>>,
 }
 
+impl Document {
+    /// Concatenate the pages of several documents into one, for example to
+    /// bundle separately compiled sources into a single PDF.
+    ///
+    /// The metadata is taken from the first document. The locations of the
+    /// elements in each document are kept apart, so headings from all
+    /// documents end up in the merged outline of an exported PDF, even if
+    /// separately compiled documents contain identical headings.
+    ///
+    /// The PDF page labels continue across documents: A document's page
+    /// labels in the same style as the last page label of the preceding
+    /// documents are offset by that label's number. Everything that is
+    /// already laid out stays as it is though, so page numbers and other
+    /// counters printed on the pages still reflect each document on its own.
+    /// For continuous printed numbering, `include` the sources into a single
+    /// document instead.
+    pub fn merge(documents: impl IntoIterator<Item = Self>) -> Self {
+        let mut documents = documents.into_iter();
+        let mut merged = documents.next().unwrap_or_default();
+        for (i, document) in documents.enumerate() {
+            let last = merged.pages.iter().rev().find_map(Frame::page_label).cloned();
+            merged.pages.extend(document.pages.into_iter().map(|mut page| {
+                page.scope_locations(1 + i);
+                if let Some(last) = &last {
+                    page.continue_page_labels(last);
+                }
+                page
+            }));
+        }
+        merged
+    }
+
+    /// The plain text of the whole document, with pages separated by form
+    /// feeds.
+    pub fn text(&self) -> EcoString {
+        let pages: Vec<_> = self.pages.iter().map(Frame::text).collect();
+        pages.join("\u{c}").into()
+    }
+
+    /// A hash of the document's output, for build systems that want to skip
+    /// downstream steps when nothing changed.
+    ///
+    /// Unlike the `Hash` implementation, this ignores source spans and
+    /// introspection metadata, so edits that don't change the output, like
+    /// adding a comment, keep the hash. A date that is left at `auto` doesn't
+    /// affect the hash either. The hash is stable for a given Typst version,
+    /// but may change between versions.
+    pub fn content_hash(&self) -> u128 {
+        let mut state = SipHasher13::new();
+        (&self.title, &self.author, &self.keywords, &self.date).hash(&mut state);
+        for page in &self.pages {
+            page.hash_output(&mut state);
+        }
+        state.finish128().as_u128()
+    }
+}
+
 /// A finished layout with items at fixed positions.
 #[derive(Default, Clone, Hash)]
 pub struct Frame {
@@ -377,6 +436,138 @@ impl Frame {
     }
 }
 
+/// Extract text.
+impl Frame {
+    /// All runs of text in the frame, in paint order, along with the position
+    /// of their baseline origin relative to the frame's top-left corner.
+    pub fn text_runs(&self) -> Vec<(Point, &TextItem)> {
+        let mut runs = vec![];
+        self.collect_text_runs(Transform::identity(), &mut runs);
+        runs
+    }
+
+    /// The plain text of the frame. Runs on different baselines are separated
+    /// by line breaks.
+    pub fn text(&self) -> EcoString {
+        let mut text = EcoString::new();
+        let mut last: Option<Abs> = None;
+        for (pos, run) in self.text_runs() {
+            if last.is_some_and(|y| !y.approx_eq(pos.y)) {
+                text.push('\n');
+            }
+            text.push_str(&run.text);
+            last = Some(pos.y);
+        }
+        text
+    }
+
+    /// Collect the text runs of this frame and its groups.
+    fn collect_text_runs<'a>(
+        &'a self,
+        ts: Transform,
+        runs: &mut Vec<(Point, &'a TextItem)>,
+    ) {
+        for (pos, item) in self.items() {
+            match item {
+                FrameItem::Group(group) => {
+                    let ts = ts
+                        .pre_concat(Transform::translate(pos.x, pos.y))
+                        .pre_concat(group.transform);
+                    group.frame.collect_text_runs(ts, runs);
+                }
+                FrameItem::Text(text) => runs.push((pos.transform(ts), text)),
+                _ => {}
+            }
+        }
+    }
+
+    /// Feed everything that ends up in an export into the hasher, skipping
+    /// source spans and introspection metadata.
+    fn hash_output(&self, state: &mut impl Hasher) {
+        self.size.hash(state);
+        for (pos, item) in self.items() {
+            if let FrameItem::Meta(
+                Meta::Elem(_) | Meta::PageNumbering(_) | Meta::Hide,
+                _,
+            ) = item
+            {
+                continue;
+            }
+
+            std::mem::discriminant(item).hash(state);
+            pos.hash(state);
+            match item {
+                FrameItem::Group(group) => {
+                    group.transform.hash(state);
+                    group.clip_path.hash(state);
+                    group.frame.hash_output(state);
+                }
+                FrameItem::Text(text) => {
+                    (&text.font, text.size, &text.fill, text.lang, &text.text)
+                        .hash(state);
+                    for glyph in &text.glyphs {
+                        (glyph.id, glyph.x_advance, glyph.x_offset, &glyph.range)
+                            .hash(state);
+                    }
+                }
+                FrameItem::Shape(shape, _) => shape.hash(state),
+                FrameItem::Image(image, size, _) => (image, size).hash(state),
+                FrameItem::Meta(meta, size) => (meta, size).hash(state),
+            }
+        }
+    }
+}
+
+/// Merging.
+impl Frame {
+    /// Move the locations of elements in this frame and of the links to them
+    /// into the given scope.
+    fn scope_locations(&mut self, scope: usize) {
+        for (_, item) in Arc::make_mut(&mut self.items) {
+            match item {
+                FrameItem::Group(group) => group.frame.scope_locations(scope),
+                FrameItem::Meta(Meta::Elem(content), _) => {
+                    if let Some(location) = content.location() {
+                        content.set_location(location.scoped(scope));
+                    }
+                }
+                FrameItem::Meta(Meta::Link(Destination::Location(location)), _) => {
+                    *location = location.scoped(scope);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Frame {
+    /// The last PDF page label in this frame.
+    fn page_label(&self) -> Option<&PdfPageLabel> {
+        self.items.iter().rev().find_map(|(_, item)| match item {
+            FrameItem::Group(group) => group.frame.page_label(),
+            FrameItem::Meta(Meta::PdfPageLabel(label), _) => Some(label),
+            _ => None,
+        })
+    }
+
+    /// Offset the PDF page labels in this frame that have the same style as
+    /// the given preceding label by that label's number.
+    fn continue_page_labels(&mut self, prev: &PdfPageLabel) {
+        let (Some(style), Some(by)) = (prev.style, prev.offset) else { return };
+        for (_, item) in Arc::make_mut(&mut self.items) {
+            match item {
+                FrameItem::Group(group) => group.frame.continue_page_labels(prev),
+                FrameItem::Meta(Meta::PdfPageLabel(label), _)
+                    if label.style == Some(style) =>
+                {
+                    label.offset = label.offset.map(|n| n.saturating_add(by.get()));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
 /// Tools for debugging.
 impl Frame {
     /// Add a full size aqua background and a red baseline for debugging.
@@ -553,6 +744,20 @@ impl TextItem {
     pub fn width(&self) -> Abs {
         self.glyphs.iter().map(|g| g.x_advance).sum::<Em>().at(self.size)
     }
+
+    /// The source location of the text at the given byte index into the
+    /// item's text.
+    ///
+    /// Together with [`lang`](Self::lang), this allows tools like
+    /// spellcheckers to work on the laid-out text and still report their
+    /// findings at the right place in the source code. The returned offset is
+    /// relative to the start of the span.
+    pub fn span_at(&self, index: usize) -> Option<(Span, u16)> {
+        self.glyphs
+            .iter()
+            .find(|glyph| glyph.range().contains(&index))
+            .map(|glyph| glyph.span)
+    }
 }
 
 impl Debug for TextItem {
@@ -885,6 +1090,121 @@ mod tests {
         assert!(!option_eq(region, "AB"));
     }
 
+    #[test]
+    fn test_content_hash_ignores_spans() {
+        let hash = |text: &str| {
+            let source = crate::syntax::Source::detached(text);
+            let span = source.root().children().last().unwrap().span();
+            let shape =
+                Geometry::Rect(Size::splat(Abs::pt(5.0))).filled(Color::BLACK.into());
+            let mut frame = Frame::soft(Size::splat(Abs::pt(10.0)));
+            frame.push(Point::zero(), FrameItem::Shape(shape, span));
+            Document { pages: vec![frame], ..Document::default() }.content_hash()
+        };
+
+        assert_eq!(hash("#rect()"), hash("// A comment.\n#rect()"));
+    }
+
+    #[test]
+    fn test_merge_keeps_locations_apart() {
+        let mut content = Content::empty();
+        content.set_location(crate::model::Locator::new().locate(1));
+        let mut frame = Frame::soft(Size::zero());
+        frame.push(Point::zero(), FrameItem::Meta(Meta::Elem(content), Size::zero()));
+
+        let document = Document { pages: vec![frame], ..Document::default() };
+        let merged = Document::merge([document.clone(), document]);
+        let introspector = crate::model::Introspector::new(&merged.pages);
+        assert_eq!(introspector.all().count(), 2);
+    }
+
+    #[test]
+    fn test_merge_continues_page_labels() {
+        let page = |style, offset| {
+            let label = PdfPageLabel {
+                prefix: None,
+                style: Some(style),
+                offset: NonZeroUsize::new(offset),
+            };
+            let mut frame = Frame::soft(Size::zero());
+            frame.push_positionless_meta(Meta::PdfPageLabel(label));
+            frame
+        };
+
+        let offsets = |document: &Document| {
+            document
+                .pages
+                .iter()
+                .map(|page| page.page_label().unwrap().offset.unwrap().get())
+                .collect::<Vec<_>>()
+        };
+
+        let arabic = PdfPageLabelStyle::Arabic;
+        let roman = PdfPageLabelStyle::LowerRoman;
+        let first = Document {
+            pages: vec![page(roman, 1), page(arabic, 1), page(arabic, 2)],
+            ..Document::default()
+        };
+        let second = Document {
+            pages: vec![page(roman, 1), page(arabic, 1)],
+            ..Document::default()
+        };
+
+        let merged = Document::merge([first.clone(), second.clone(), second]);
+        assert_eq!(offsets(&merged), [1, 1, 2, 1, 3, 1, 4]);
+        assert_eq!(offsets(&Document::merge([first])), [1, 1, 2]);
+    }
+
+    #[test]
+    fn test_text_runs_in_paint_order() {
+        let data = include_bytes!("../../../assets/fonts/IBMPlexSans-Regular.ttf");
+        let font = Font::new(crate::eval::Bytes::from_static(data), 0).unwrap();
+        let text = |text: &str| {
+            FrameItem::Text(TextItem {
+                font: font.clone(),
+                size: Abs::pt(10.0),
+                fill: Color::BLACK.into(),
+                lang: Lang::ENGLISH,
+                text: text.into(),
+                glyphs: vec![],
+            })
+        };
+        let pt = |x, y| Point::new(Abs::pt(x), Abs::pt(y));
+
+        // The scaled group's runs are placed relative to the page.
+        let mut inner = Frame::soft(Size::splat(Abs::pt(50.0)));
+        inner.push(pt(5.0, 10.0), text("line"));
+        inner.push(pt(30.0, 10.0), text("two"));
+        let mut group = GroupItem::new(inner);
+        group.transform = Transform::scale(geom::Ratio::new(2.0), geom::Ratio::new(2.0));
+
+        let mut first = Frame::soft(Size::splat(Abs::pt(100.0)));
+        first.push(pt(10.0, 20.0), text("First"));
+        first.push(pt(0.0, 40.0), FrameItem::Group(group));
+        first.push(pt(10.0, 80.0), text("Third"));
+
+        let mut second = Frame::soft(Size::splat(Abs::pt(100.0)));
+        second.push(pt(10.0, 20.0), text("Last"));
+
+        let runs: Vec<_> = first
+            .text_runs()
+            .into_iter()
+            .map(|(pos, run)| (run.text.as_str(), pos))
+            .collect();
+        assert_eq!(
+            runs,
+            [
+                ("First", pt(10.0, 20.0)),
+                ("line", pt(10.0, 60.0)),
+                ("two", pt(60.0, 60.0)),
+                ("Third", pt(10.0, 80.0)),
+            ]
+        );
+
+        let document = Document { pages: vec![first, second], ..Document::default() };
+        assert_eq!(document.text(), "First\nlinetwo\nThird\u{c}Last");
+    }
+
     #[test]
     fn test_document_is_send() {
         fn ensure_send<T: Send>() {}
@@ -2,7 +2,7 @@ use std::fmt::{self, Debug, Formatter};
 
 use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 
-use crate::diag::{bail, At, SourceDiagnostic, SourceResult};
+use crate::diag::{bail, error, At, SourceDiagnostic, SourceResult};
 use crate::eval::{
     func, repr, scope, ty, Array, Dict, FromValue, IntoValue, Repr, Str, Value,
 };
@@ -105,7 +105,7 @@ impl Args {
         }
 
         if list.len() < n {
-            bail!(self.span, "not enough arguments");
+            bail!(error!(self.span, "not enough arguments").with_code("E0102"));
         }
 
         Ok(list)
@@ -121,7 +121,9 @@ impl Args {
     {
         match self.eat()? {
             Some(v) => Ok(v),
-            None => bail!(self.span, "missing argument: {what}"),
+            None => {
+                bail!(error!(self.span, "missing argument: {what}").with_code("E0102"))
+            }
         }
     }
 
@@ -210,10 +212,11 @@ impl Args {
     /// argument.
     pub fn finish(self) -> SourceResult<()> {
         if let Some(arg) = self.items.first() {
-            match &arg.name {
-                Some(name) => bail!(arg.span, "unexpected argument: {name}"),
-                _ => bail!(arg.span, "unexpected argument"),
-            }
+            let error = match &arg.name {
+                Some(name) => error!(arg.span, "unexpected argument: {name}"),
+                _ => error!(arg.span, "unexpected argument"),
+            };
+            bail!(error.with_code("E0102"));
         }
         Ok(())
     }
@@ -7,12 +7,14 @@ use ecow::{eco_format, EcoString, EcoVec};
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 
-use crate::diag::{At, SourceResult, StrResult};
+use crate::diag::{At, HintedStrResult, SourceResult, StrResult};
+use crate::doc::Lang;
 use crate::eval::{
-    cast, func, ops, repr, scope, ty, Args, Bytes, CastInfo, FromValue, Func, IntoValue,
-    Reflect, Repr, Value, Version, Vm,
+    cast, func, locale, ops, repr, scope, ty, Args, Bytes, CastInfo, FromValue, Func,
+    IntoValue, Reflect, Repr, Value, Version, Vm,
 };
 use crate::syntax::Span;
+use crate::util::Rng;
 
 /// Create a new [`Array`] from values.
 #[macro_export]
@@ -544,7 +546,7 @@ impl Array {
         /// be empty.
         #[named]
         default: Option<Value>,
-    ) -> StrResult<Value> {
+    ) -> HintedStrResult<Value> {
         let mut iter = self.into_iter();
         let mut acc = iter
             .next()
@@ -649,6 +651,12 @@ impl Array {
     }
 
     /// Combine all items in the array into one.
+    ///
+    /// ```example
+    /// #let names = ("Ada", "Grace", "Hedy")
+    /// #names.join(", ", last: " and ") \
+    /// #names.join(", ", lang: "fr")
+    /// ```
     #[func]
     pub fn join(
         self,
@@ -658,11 +666,18 @@ impl Array {
         /// An alternative separator between the last two items.
         #[named]
         last: Option<Value>,
+        /// A language whose word for "and" to use as the separator between the
+        /// last two items if `last` is omitted. Typically, you'll want to pass
+        /// the document's language here.
+        #[named]
+        #[default]
+        lang: Option<Lang>,
     ) -> StrResult<Value> {
         let len = self.0.len();
         let separator = separator.unwrap_or(Value::None);
 
-        let mut last = last;
+        let mut last = last
+            .or_else(|| lang.map(|lang| Value::Str(locale::conjunction(lang).into())));
         let mut result = Value::None;
         for (i, value) in self.into_iter().enumerate() {
             if i > 0 {
@@ -753,6 +768,31 @@ impl Array {
         result.map(|_| vec.into())
     }
 
+    /// Returns a shuffled version of this array.
+    ///
+    /// The order only depends on the seed, so the same seed always yields the
+    /// same order. This keeps documents reproducible, for example when
+    /// generating several variants of an exam.
+    ///
+    /// ```example
+    /// #(1, 2, 3, 4, 5).shuffled(7) \
+    /// #(1, 2, 3, 4, 5).shuffled(8)
+    /// ```
+    #[func]
+    pub fn shuffled(
+        self,
+        /// The seed that determines the order.
+        seed: i64,
+    ) -> Array {
+        let mut vec = self.0;
+        let items = vec.make_mut();
+        let mut rng = Rng::new(seed as u64);
+        for i in (1..items.len()).rev() {
+            items.swap(i, rng.below(i + 1));
+        }
+        vec.into()
+    }
+
     /// Deduplicates all items in the array.
     ///
     /// Returns a new array with all duplicate items removed. Only the first
@@ -800,6 +840,49 @@ impl Array {
 
         Ok(Self(out))
     }
+
+    /// Groups the items of the array by a key.
+    ///
+    /// Returns an array of pairs, one for each distinct key in order of first
+    /// appearance. Each pair consists of the key and an array of all items
+    /// that share it, in their original order.
+    ///
+    /// ```example
+    /// #let sales = (
+    ///   (region: "North", amount: 10),
+    ///   (region: "South", amount: 5),
+    ///   (region: "North", amount: 7),
+    /// )
+    ///
+    /// #for (region, rows) in sales.group-by(row => row.region) [
+    ///   #region: #rows.map(row => row.amount).sum() \
+    /// ]
+    /// ```
+    #[func]
+    pub fn group_by(
+        self,
+        /// The virtual machine.
+        vm: &mut Vm,
+        /// The function to apply to each item to determine its key.
+        key: Func,
+    ) -> SourceResult<Array> {
+        let mut groups: Vec<(Value, EcoVec<Value>)> = vec![];
+
+        // Like in `dedup`, we can't hash arbitrary values, so this is O(N^2)
+        // in the number of distinct keys.
+        for item in self {
+            let k = key.call_vm(vm, Args::new(key.span(), [item.clone()]))?;
+            match groups.iter_mut().find(|(other, _)| super::ops::equal(&k, other)) {
+                Some((_, items)) => items.push(item),
+                None => groups.push((k, eco_vec![item])),
+            }
+        }
+
+        Ok(groups
+            .into_iter()
+            .map(|(k, items)| array![k, Value::Array(Self(items))].into_value())
+            .collect())
+    }
 }
 
 /// A value that can be cast to bytes.
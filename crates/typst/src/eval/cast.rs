@@ -10,7 +10,7 @@ use ecow::{eco_format, EcoString};
 use smallvec::SmallVec;
 use unicode_math_class::MathClass;
 
-use crate::diag::{At, SourceResult, StrResult};
+use crate::diag::{At, HintedStrResult, SourceResult, StrResult};
 use crate::eval::{repr, Repr, Type, Value};
 use crate::syntax::{Span, Spanned};
 
@@ -110,6 +110,20 @@ impl<T: Reflect> Reflect for StrResult<T> {
     }
 }
 
+impl<T: Reflect> Reflect for HintedStrResult<T> {
+    fn input() -> CastInfo {
+        T::input()
+    }
+
+    fn output() -> CastInfo {
+        T::output()
+    }
+
+    fn castable(value: &Value) -> bool {
+        T::castable(value)
+    }
+}
+
 impl<T: Reflect> Reflect for SourceResult<T> {
     fn input() -> CastInfo {
         T::input()
@@ -205,6 +219,12 @@ impl<T: IntoValue> IntoResult for StrResult<T> {
     }
 }
 
+impl<T: IntoValue> IntoResult for HintedStrResult<T> {
+    fn into_result(self, span: Span) -> SourceResult<Value> {
+        self.map(IntoValue::into_value).at(span)
+    }
+}
+
 impl<T: IntoValue> IntoResult for SourceResult<T> {
     fn into_result(self, _: Span) -> SourceResult<Value> {
         self.map(IntoValue::into_value)
@@ -10,8 +10,9 @@ use time::macros::format_description;
 use time::{format_description, Month, PrimitiveDateTime};
 
 use crate::diag::{bail, StrResult};
+use crate::doc::Lang;
 use crate::eval::{
-    cast, func, repr, scope, ty, Dict, Duration, Repr, Smart, Str, Value, Vm,
+    cast, func, locale, repr, scope, ty, Dict, Duration, Repr, Smart, Str, Value, Vm,
 };
 use crate::World;
 
@@ -319,13 +320,43 @@ impl Datetime {
     /// `[[year]-[month]-[day]]`. If you specified a time, it will be
     /// `[[hour]:[minute]:[second]]`. In the case of a datetime, it will be
     /// `[[year]-[month]-[day] [hour]:[minute]:[second]]`.
+    ///
+    /// ```example
+    /// #let date = datetime(year: 2023, month: 5, day: 1)
+    /// #date.display("[weekday], [month repr:long] [day padding:none]") \
+    /// #date.display("[weekday], [day padding:none]. [month repr:long]", lang: "de")
+    ///
+    /// #set text(lang: "fr")
+    /// #date.display("[weekday repr:short] [day padding:none] [month repr:short]")
+    /// ```
     #[func]
     pub fn display(
         &self,
+        /// The virtual machine.
+        vm: &mut Vm,
         /// The format used to display the datetime.
         #[default]
         pattern: Smart<DisplayPattern>,
+        /// A language in which to write the names of months and weekdays, that
+        /// is, the `[[month repr:long]]`, `[[month repr:short]]`, and
+        /// `[[weekday]]` components. If omitted, the language of the
+        /// surrounding `{set text(lang: ..)}` rules is used. For unsupported
+        /// languages, English names are used.
+        #[named]
+        #[default]
+        lang: Option<Lang>,
     ) -> StrResult<EcoString> {
+        let lang = lang.unwrap_or_else(|| vm.lang());
+        let pattern = match pattern {
+            Smart::Custom(DisplayPattern(text, _)) if lang != Lang::ENGLISH => {
+                let text = localize_pattern(&text, lang, self.month(), self.weekday());
+                let format = format_description::parse_owned::<2>(&text)
+                    .map_err(format_time_invalid_format_description_error)?;
+                Smart::Custom(DisplayPattern(text.into(), format))
+            }
+            pattern => pattern,
+        };
+
         let pat = |s| format_description::parse_borrowed::<2>(s).unwrap();
         let result = match pattern {
             Smart::Auto => match self {
@@ -543,6 +574,57 @@ cast! {
     }
 }
 
+/// Replace the month and weekday name components in a format description with
+/// the names in the given language.
+fn localize_pattern(
+    pattern: &str,
+    lang: Lang,
+    month: Option<u8>,
+    weekday: Option<u8>,
+) -> EcoString {
+    let mut out = EcoString::new();
+    let mut rest = pattern;
+    while let Some(start) = rest.find(['\\', '[']) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // Keep escape sequences as they are.
+        if rest.starts_with('\\') {
+            let len = 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+            out.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+
+        let end = rest.find(']').map_or(rest.len(), |i| i + 1);
+        let component = &rest[..end];
+        let mut parts = component.trim_matches(['[', ']']).split_whitespace();
+        let name = parts.next();
+        let repr = parts.find_map(|modifier| modifier.strip_prefix("repr:"));
+        let localized = match (name, repr) {
+            (Some("month"), Some("long")) => {
+                month.and_then(|month| locale::month_name(lang, month))
+            }
+            (Some("month"), Some("short")) => {
+                month.and_then(|month| locale::short_month_name(lang, month))
+            }
+            (Some("weekday"), None | Some("long")) => {
+                weekday.and_then(|weekday| locale::weekday_name(lang, weekday))
+            }
+            (Some("weekday"), Some("short")) => {
+                weekday.and_then(|weekday| locale::short_weekday_name(lang, weekday))
+            }
+            _ => None,
+        };
+
+        out.push_str(localized.unwrap_or(component));
+        rest = &rest[end..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
 /// Format the `Format` error of the time crate in an appropriate way.
 fn format_time_format_error(error: Format) -> EcoString {
     match error {
@@ -0,0 +1,436 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Formatter};
+use std::str::FromStr;
+
+use ecow::{eco_format, EcoString};
+
+use crate::diag::{bail, StrResult};
+use crate::doc::Lang;
+use crate::eval::{cast, func, locale, repr, scope, ty, Repr, Str};
+
+/// The maximum number of digits after the decimal point.
+const MAX_SCALE: u32 = 28;
+
+/// An exact decimal number.
+///
+/// In contrast to [floats]($float), decimals represent numbers like `0.1`
+/// exactly, so that sums of prices, taxes, and totals don't pick up rounding
+/// artifacts. A decimal stores up to 28 digits after the decimal point.
+///
+/// Decimals can be added, subtracted, multiplied, divided, and compared with
+/// other decimals and with [integers]($int). Division rounds to 28 decimal
+/// places. Mixing decimals with floats is not allowed, as the result would no
+/// longer be exact; convert explicitly with the [`float`]($float) constructor
+/// instead.
+///
+/// # Example
+/// ```example
+/// #let price = decimal("19.99")
+/// #let total = price * 3 + decimal("0.1") + decimal("0.2")
+/// #total.display(digits: 2) \
+/// #(decimal("0.1") + decimal("0.2") == decimal("0.3"))
+/// ```
+#[ty(scope)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Decimal {
+    /// The digits of the number, without the decimal point.
+    mantissa: i128,
+    /// How many of the mantissa's digits are after the decimal point.
+    scale: u32,
+}
+
+impl Decimal {
+    /// Create a decimal with value `mantissa * 10^-scale`.
+    ///
+    /// Trailing zeros after the decimal point are dropped, so that every
+    /// number has exactly one representation.
+    pub fn new(mut mantissa: i128, mut scale: u32) -> Self {
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Self { mantissa, scale }
+    }
+
+    /// Whether the decimal is zero.
+    pub fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// The closest float to this decimal.
+    pub fn to_f64(self) -> f64 {
+        self.format(None, "", ".", "-").parse().unwrap_or(f64::NAN)
+    }
+
+    /// Try to negate the decimal.
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::new(self.mantissa.checked_neg()?, self.scale))
+    }
+
+    /// Try to add two decimals.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let a = rescale(self.mantissa, self.scale, scale)?;
+        let b = rescale(other.mantissa, other.scale, scale)?;
+        Some(Self::new(a.checked_add(b)?, scale))
+    }
+
+    /// Try to subtract a decimal from this one.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    /// Try to multiply two decimals.
+    ///
+    /// Rounds to 28 decimal places if the exact product has more.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        // The exact product may not fit into 128 bits before rounding, so we
+        // compute it with 256 bits.
+        let (hi, lo) =
+            widening_mul(self.mantissa.unsigned_abs(), other.mantissa.unsigned_abs());
+        let scale = self.scale + other.scale;
+        let excess = scale.saturating_sub(MAX_SCALE);
+        let factor = 10_u128.pow(excess);
+        let (mut magnitude, rest) = wide_div(hi, lo, factor)?;
+
+        // Round half away from zero.
+        if rest != 0 && rest >= factor - rest {
+            magnitude = magnitude.checked_add(1)?;
+        }
+
+        let mantissa = i128::try_from(magnitude).ok()?;
+        let sign = self.mantissa.signum() * other.mantissa.signum();
+        Some(Self::new(mantissa * sign, scale - excess))
+    }
+
+    /// Try to divide this decimal by another one.
+    ///
+    /// Rounds to 28 decimal places if the exact quotient has more. Returns
+    /// `None` if the divisor is zero or the result doesn't fit.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+
+        // Long division, one digit after the decimal point at a time.
+        let divisor = other.mantissa;
+        let mut quotient = self.mantissa.checked_div(divisor)?;
+        let mut remainder = self.mantissa.checked_rem(divisor)?;
+        let mut scale = self.scale as i64 - other.scale as i64;
+        while remainder != 0 && scale < MAX_SCALE as i64 {
+            let Some(shifted) = remainder.checked_mul(10) else { break };
+            let Some(next) = quotient
+                .checked_mul(10)
+                .and_then(|q| q.checked_add(shifted / divisor))
+            else {
+                break;
+            };
+            quotient = next;
+            remainder = shifted % divisor;
+            scale += 1;
+        }
+
+        // Round half away from zero.
+        let rest = remainder.unsigned_abs();
+        if rest != 0 && rest >= divisor.unsigned_abs() - rest {
+            let sign = self.mantissa.signum() * divisor.signum();
+            quotient = quotient.checked_add(sign)?;
+        }
+
+        if scale < 0 {
+            let factor = 10_i128.checked_pow((-scale) as u32)?;
+            Some(Self::new(quotient.checked_mul(factor)?, 0))
+        } else {
+            Some(Self::new(quotient, scale as u32))
+        }
+    }
+
+    /// Format the decimal with the given options.
+    fn format(
+        self,
+        digits: Option<u8>,
+        separator: &str,
+        decimal_mark: &str,
+        minus: &str,
+    ) -> EcoString {
+        let mut mantissa = self.mantissa;
+        let mut scale = self.scale;
+        if let Some(digits) = digits.map(u32::from) {
+            if digits < scale {
+                mantissa = round_off(mantissa, scale - digits);
+                scale = digits;
+            }
+        }
+
+        let mut raw = mantissa.unsigned_abs().to_string();
+        let scale = scale as usize;
+        if raw.len() <= scale {
+            raw.insert_str(0, &"0".repeat(scale + 1 - raw.len()));
+        }
+
+        let (int, frac) = raw.split_at(raw.len() - scale);
+        let mut out = EcoString::new();
+        if mantissa < 0 {
+            out.push_str(minus);
+        }
+
+        for (i, c) in int.chars().enumerate() {
+            if i > 0 && (int.len() - i) % 3 == 0 {
+                out.push_str(separator);
+            }
+            out.push(c);
+        }
+
+        let padding = digits.map_or(0, |d| usize::from(d).saturating_sub(scale));
+        if !frac.is_empty() || padding > 0 {
+            out.push_str(decimal_mark);
+            out.push_str(frac);
+            for _ in 0..padding {
+                out.push('0');
+            }
+        }
+
+        out
+    }
+}
+
+#[scope]
+impl Decimal {
+    /// Converts a value to a decimal.
+    ///
+    /// - Integers are converted exactly.
+    /// - Floats are converted to the decimal with the fewest digits that
+    ///   rounds back to the same float, so `{decimal(0.1)}` is exactly `0.1`.
+    /// - Strings are parsed in base 10, with an optional sign and decimal
+    ///   point. This is the most precise way to write down a decimal.
+    ///
+    /// ```example
+    /// #decimal("12.50") \
+    /// #decimal(3) \
+    /// #decimal(0.1)
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The value that should be converted to a decimal.
+        value: ToDecimal,
+    ) -> Decimal {
+        value.0
+    }
+
+    /// Formats this decimal for display in the document.
+    ///
+    /// ```example
+    /// #let amount = decimal("1234567.891")
+    /// #amount.display(digits: 2) \
+    /// #amount.display(separator: ",", digits: 2) \
+    /// #amount.display(
+    ///   separator: ".",
+    ///   decimal-mark: ",",
+    ///   digits: 2,
+    /// ) \
+    /// #amount.display(lang: "fr", digits: 2)
+    /// ```
+    #[func]
+    pub fn display(
+        self,
+        /// The number of decimal places to display. The decimal is rounded
+        /// half away from zero or padded with trailing zeros as needed. If
+        /// omitted, all of its digits are displayed.
+        #[named]
+        #[default]
+        digits: Option<u8>,
+        /// The thousands separator to insert between groups of three digits
+        /// before the decimal point. Defaults to the language's separator if
+        /// `lang` is given and to no separator otherwise.
+        #[named]
+        #[default]
+        separator: Option<Str>,
+        /// The string to use as the decimal point. Defaults to the language's
+        /// decimal mark if `lang` is given and to `{"."}` otherwise.
+        #[named]
+        #[default]
+        decimal_mark: Option<Str>,
+        /// A language whose conventions for the decimal mark and digit
+        /// grouping to follow. Typically, you'll want to pass the document's
+        /// language here.
+        #[named]
+        #[default]
+        lang: Option<Lang>,
+    ) -> Str {
+        let separator = separator
+            .as_deref()
+            .or_else(|| lang.map(locale::group_separator))
+            .unwrap_or("");
+        let decimal_mark = decimal_mark
+            .as_deref()
+            .or_else(|| lang.map(locale::decimal_mark))
+            .unwrap_or(".");
+        self.format(digits, separator, decimal_mark, repr::MINUS_SIGN).into()
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(v: i64) -> Self {
+        Self::new(v.into(), 0)
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = EcoString;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.replace(repr::MINUS_SIGN, "-");
+        let (negative, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(&text)),
+        };
+
+        let (int, frac) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if (int.is_empty() && frac.is_empty())
+            || !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit())
+        {
+            bail!("invalid decimal: {}", s);
+        }
+
+        if frac.len() > MAX_SCALE as usize {
+            bail!("decimal has more than {} digits after the decimal point", MAX_SCALE);
+        }
+
+        let mut mantissa: i128 = 0;
+        for c in int.chars().chain(frac.chars()) {
+            let digit = c as i128 - '0' as i128;
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(digit))
+                .ok_or("decimal is too large")?;
+        }
+
+        if negative {
+            mantissa = -mantissa;
+        }
+
+        Ok(Self::new(mantissa, frac.len() as u32))
+    }
+}
+
+impl TryFrom<f64> for Decimal {
+    type Error = EcoString;
+
+    fn try_from(v: f64) -> Result<Self, Self::Error> {
+        if !v.is_finite() {
+            bail!("cannot convert {} to a decimal", v.repr());
+        }
+
+        // Use the shortest representation that round-trips, unless that has
+        // too many digits after the decimal point.
+        let shortest = v.to_string();
+        let too_precise = shortest
+            .split_once('.')
+            .map_or(false, |(_, frac)| frac.len() > MAX_SCALE as usize);
+        if too_precise {
+            eco_format!("{:.*}", MAX_SCALE as usize, v).parse()
+        } else {
+            shortest.parse()
+        }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare the integral parts first, then the fractional parts at a
+        // common scale. Both parts carry the sign of the number, so this
+        // can't overflow.
+        let split = |d: &Self, scale: u32| {
+            let factor = 10_i128.pow(d.scale);
+            let frac = (d.mantissa % factor) * 10_i128.pow(scale - d.scale);
+            (d.mantissa / factor, frac)
+        };
+
+        let scale = self.scale.max(other.scale);
+        split(self, scale).cmp(&split(other, scale))
+    }
+}
+
+impl Debug for Decimal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.format(None, "", ".", "-"))
+    }
+}
+
+impl Repr for Decimal {
+    fn repr(&self) -> EcoString {
+        eco_format!("decimal({})", self.format(None, "", ".", "-").repr())
+    }
+}
+
+/// A value that can be cast to a decimal.
+pub struct ToDecimal(Decimal);
+
+cast! {
+    ToDecimal,
+    v: Decimal => Self(v),
+    v: i64 => Self(v.into()),
+    v: f64 => Self(v.try_into()?),
+    v: Str => Self(v.parse()?),
+}
+
+/// Scale a mantissa up to a larger scale.
+fn rescale(mantissa: i128, from: u32, to: u32) -> Option<i128> {
+    mantissa.checked_mul(10_i128.checked_pow(to - from)?)
+}
+
+/// Remove `digits` digits from the end of a mantissa, rounding half away from
+/// zero.
+fn round_off(mantissa: i128, digits: u32) -> i128 {
+    let Some(factor) = 10_i128.checked_pow(digits) else { return 0 };
+    let quotient = mantissa / factor;
+    let rest = (mantissa % factor).unsigned_abs();
+    if rest >= factor.unsigned_abs() - rest {
+        quotient + mantissa.signum()
+    } else {
+        quotient
+    }
+}
+
+/// Multiply two numbers into a 256-bit product, returned as its high and low
+/// halves.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & MASK);
+    let (b1, b0) = (b >> 64, b & MASK);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Divide a 256-bit number, given as its high and low halves, by a divisor
+/// below `2^127`. Returns the quotient and remainder, or `None` if the quotient
+/// doesn't fit into 128 bits.
+fn wide_div(hi: u128, lo: u128, divisor: u128) -> Option<(u128, u128)> {
+    if hi >= divisor {
+        return None;
+    }
+
+    // Binary long division. The remainder stays below the divisor, so
+    // shifting it never overflows.
+    let mut quotient = 0;
+    let mut remainder = hi;
+    for i in (0..128).rev() {
+        remainder = (remainder << 1) | ((lo >> i) & 1);
+        quotient <<= 1;
+        if remainder >= divisor {
+            remainder -= divisor;
+            quotient |= 1;
+        }
+    }
+
+    Some((quotient, remainder))
+}
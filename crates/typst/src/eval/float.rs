@@ -2,7 +2,8 @@ use std::num::ParseFloatError;
 
 use ecow::{eco_format, EcoString};
 
-use crate::eval::{cast, func, repr, scope, ty, Repr, Str};
+use crate::doc::Lang;
+use crate::eval::{cast, func, locale, repr, scope, ty, Decimal, Repr, Str};
 use crate::geom::Ratio;
 
 /// A floating-point number.
@@ -28,6 +29,7 @@ impl f64 {
     ///
     /// - Booleans are converted to `0.0` or `1.0`.
     /// - Integers are converted to the closest 64-bit float.
+    /// - Decimals are converted to the closest 64-bit float.
     /// - Ratios are divided by 100%.
     /// - Strings are parsed in base 10 to the closest 64-bit float.
     ///   Exponential notation is supported.
@@ -47,6 +49,40 @@ impl f64 {
     ) -> f64 {
         value.0
     }
+
+    /// Formats this float for display in the document.
+    ///
+    /// If `digits` is given, the number is rounded to that many decimal places
+    /// and padded with trailing zeros, so that numbers in a table line up.
+    /// Otherwise, it is displayed with as many digits as needed.
+    ///
+    /// ```example
+    /// #calc.pi.display(digits: 3) \
+    /// #(1.5).display(digits: 2) \
+    /// #(1 / 8).display() \
+    /// #(12345.678).display(lang: "de")
+    /// ```
+    #[func]
+    pub fn display(
+        self,
+        /// The number of decimal places to display.
+        #[named]
+        #[default]
+        digits: Option<u8>,
+        /// A language whose conventions for the decimal mark and digit
+        /// grouping to follow. Typically, you'll want to pass the document's
+        /// language here. If omitted, a period is used as the decimal mark
+        /// and digits are not grouped.
+        #[named]
+        #[default]
+        lang: Option<Lang>,
+    ) -> Str {
+        let number = repr::display_float(self, digits, "");
+        match lang {
+            Some(lang) => locale::format_number(&number, lang).into(),
+            None => number.into(),
+        }
+    }
 }
 
 impl Repr for f64 {
@@ -63,6 +99,7 @@ cast! {
     v: bool => Self(v as i64 as f64),
     v: i64 => Self(v as f64),
     v: Ratio => Self(v.get()),
+    v: Decimal => Self(v.to_f64()),
     v: Str => Self(
         parse_float(v.clone().into())
             .map_err(|_| eco_format!("invalid float: {}", v))?
@@ -2,10 +2,10 @@ use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
 
 use comemo::{Prehashed, Tracked, TrackedMut};
-use ecow::{eco_format, EcoString};
+use ecow::{eco_format, EcoString, EcoVec};
 use once_cell::sync::Lazy;
 
-use crate::diag::{bail, HintedStrResult, SourceResult, StrResult};
+use crate::diag::{bail, HintedStrResult, SourceDiagnostic, SourceResult, StrResult};
 use crate::eval::{
     cast, scope, ty, Args, CastInfo, Eval, FlowEvent, IntoValue, Route, Scope, Scopes,
     Tracer, Type, Value, Vm,
@@ -577,15 +577,17 @@ impl Closure {
             match p {
                 ast::Param::Pos(pattern) => match pattern {
                     ast::Pattern::Normal(ast::Expr::Ident(ident)) => {
-                        vm.define(ident, args.expect::<Value>(&ident)?)
+                        let value = args
+                            .expect::<Value>(&ident)
+                            .map_err(declared_here(ident.span(), "parameter"))?;
+                        vm.define(ident, value)
                     }
                     ast::Pattern::Normal(_) => unreachable!(),
                     pattern => {
-                        super::define_pattern(
-                            &mut vm,
-                            pattern,
-                            args.expect::<Value>("pattern parameter")?,
-                        )?;
+                        let value = args
+                            .expect::<Value>("pattern parameter")
+                            .map_err(declared_here(pattern.span(), "parameter"))?;
+                        super::define_pattern(&mut vm, pattern, value)?;
                     }
                 },
                 ast::Param::Sink(ident) => {
@@ -613,7 +615,8 @@ impl Closure {
         }
 
         // Ensure all arguments have been used.
-        args.finish()?;
+        args.finish()
+            .map_err(declared_here(closure.params().span(), "parameters"))?;
 
         // Handle control flow.
         let output = closure.body().eval(&mut vm)?;
@@ -628,6 +631,20 @@ impl Closure {
     }
 }
 
+/// Point the errors of a closure's argument parsing to the parameters they
+/// concern.
+fn declared_here(
+    span: Span,
+    what: &'static str,
+) -> impl FnOnce(EcoVec<SourceDiagnostic>) -> EcoVec<SourceDiagnostic> {
+    move |errors| {
+        errors
+            .into_iter()
+            .map(|error| error.with_related(span, eco_format!("{what} declared here")))
+            .collect()
+    }
+}
+
 impl From<Closure> for Func {
     fn from(closure: Closure) -> Self {
         Repr::Closure(Arc::new(Prehashed::new(closure))).into()
@@ -796,7 +813,11 @@ impl<'a> CapturesVisitor<'a> {
                 return;
             };
 
-            self.captures.define_captured(ident, value.clone());
+            let span = self
+                .external
+                .and_then(|external| external.span(ident))
+                .unwrap_or_else(Span::detached);
+            self.captures.define_captured(ident, value.clone(), span);
         }
     }
 }
@@ -2,7 +2,9 @@ use std::num::{NonZeroI64, NonZeroIsize, NonZeroU64, NonZeroUsize, ParseIntError
 
 use ecow::{eco_format, EcoString};
 
-use crate::eval::{cast, func, repr, scope, ty, Repr, Str, Value};
+use crate::diag::StrResult;
+use crate::doc::Lang;
+use crate::eval::{cast, func, locale, repr, scope, ty, Cast, Repr, Str, Value};
 
 /// A whole number.
 ///
@@ -49,6 +51,71 @@ impl i64 {
     ) -> i64 {
         value.0
     }
+
+    /// Formats this integer for display in the document.
+    ///
+    /// Numbers can be displayed as digits, spelled out in words, or as
+    /// ordinals. For other systems like roman numerals, use the
+    /// [`numbering`]($numbering) function.
+    ///
+    /// ```example
+    /// #(1234567).display(lang: "de") \
+    /// #(42).display(form: "spell-out") \
+    /// #(1002).display(form: "spell-out") \
+    /// #(22).display(form: "ordinal") \
+    /// #(3).display(form: "ordinal", lang: "fr")
+    /// ```
+    #[func]
+    pub fn display(
+        self,
+        /// How to display the number.
+        #[named]
+        #[default(IntForm::Digits)]
+        form: IntForm,
+        /// A language whose conventions to follow. Typically, you'll want to
+        /// pass the document's language here. If omitted, digits are not
+        /// grouped and words and ordinals are in English.
+        #[named]
+        #[default]
+        lang: Option<Lang>,
+    ) -> StrResult<Str> {
+        let number = match form {
+            IntForm::Digits => {
+                let digits = repr::format_int_with_base(self, 10);
+                match lang {
+                    Some(lang) => locale::format_number(&digits, lang),
+                    None => digits,
+                }
+            }
+            IntForm::SpellOut => {
+                let lang = lang.unwrap_or(Lang::ENGLISH);
+                locale::spell_out(self, lang).ok_or_else(|| {
+                    eco_format!(
+                        "cannot spell out numbers in language `{}`",
+                        lang.as_str()
+                    )
+                })?
+            }
+            IntForm::Ordinal => {
+                let lang = lang.unwrap_or(Lang::ENGLISH);
+                locale::ordinal(self, lang).ok_or_else(|| {
+                    eco_format!("cannot form ordinals in language `{}`", lang.as_str())
+                })?
+            }
+        };
+        Ok(number.into())
+    }
+}
+
+/// How to display an integer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum IntForm {
+    /// As digits, like "42".
+    Digits,
+    /// Spelled out in words, like "forty-two".
+    SpellOut,
+    /// As an ordinal, like "42nd".
+    Ordinal,
 }
 
 impl Repr for i64 {
@@ -7,7 +7,7 @@ use ecow::EcoString;
 use std::sync::OnceLock;
 
 use crate::diag::SourceResult;
-use crate::doc::Document;
+use crate::doc::{Document, Lang};
 use crate::eval::Module;
 use crate::geom::{Abs, Dir};
 use crate::model::{Content, Element, Introspector, Label, StyleChain, Styles, Vt};
@@ -36,6 +36,8 @@ pub struct LangItems {
     pub em: fn(StyleChain) -> Abs,
     /// Access the text direction.
     pub dir: fn(StyleChain) -> Dir,
+    /// Access the text language.
+    pub lang: fn(StyleChain) -> Lang,
     /// Whitespace.
     pub space: fn() -> Content,
     /// A forced line break: `\`.
@@ -116,6 +118,7 @@ impl Hash for LangItems {
         (self.layout as usize).hash(state);
         (self.em as usize).hash(state);
         (self.dir as usize).hash(state);
+        (self.lang as usize).hash(state);
         self.space.hash(state);
         self.linebreak.hash(state);
         self.text.hash(state);
@@ -0,0 +1,296 @@
+//! Language-specific conventions for formatting numbers, dates, and lists.
+
+use ecow::{eco_format, EcoString};
+
+use crate::doc::Lang;
+use crate::eval::repr::{self, MINUS_SIGN};
+
+/// The symbol that separates the integer part of a number from its fraction.
+pub fn decimal_mark(lang: Lang) -> &'static str {
+    // Languages that group digits with commas use a period as the decimal
+    // mark and all others use a comma.
+    if group_separator(lang) == "," {
+        "."
+    } else {
+        ","
+    }
+}
+
+/// The symbol that separates groups of three digits in the integer part of a
+/// number.
+pub fn group_separator(lang: Lang) -> &'static str {
+    match lang.as_str() {
+        "de" | "nl" | "it" | "es" | "pt" | "da" | "tr" | "sl" | "ro" | "el" | "gr"
+        | "vi" | "id" => ".",
+        "fr" => "\u{202F}",
+        "sv" | "nb" | "nn" | "fi" | "pl" | "cs" | "sk" | "ru" | "uk" | "ua" | "hu"
+        | "sq" => "\u{A0}",
+        _ => ",",
+    }
+}
+
+/// The separator between the last two items of a list, as in "A, B and C",
+/// including surrounding spaces if the language uses them.
+pub fn conjunction(lang: Lang) -> &'static str {
+    match lang.as_str() {
+        "de" => " und ",
+        "fr" => " et ",
+        "es" => " y ",
+        "it" | "pt" => " e ",
+        "nl" => " en ",
+        "sv" => " och ",
+        "da" | "nb" | "nn" => " og ",
+        "fi" => " ja ",
+        "pl" => " i ",
+        "cs" | "sk" => " a ",
+        "ru" => " и ",
+        "uk" | "ua" => " і ",
+        "tr" => " ve ",
+        "sl" => " in ",
+        "ro" => " și ",
+        "hu" => " és ",
+        "el" | "gr" => " και ",
+        "sq" => " dhe ",
+        "vi" => " và ",
+        "tl" => " at ",
+        "zh" => "和",
+        "ja" => "と",
+        "ar" => " و",
+        _ => " and ",
+    }
+}
+
+/// Localize a number that was formatted with a `.` as the decimal mark and
+/// without digit grouping.
+pub fn format_number(number: &str, lang: Lang) -> EcoString {
+    let (sign, unsigned) = match number.strip_prefix(MINUS_SIGN) {
+        Some(rest) => (MINUS_SIGN, rest),
+        None => ("", number),
+    };
+
+    let (int, frac) = match unsigned.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (unsigned, None),
+    };
+
+    // Leave things like `inf` and `NaN` alone.
+    if !int.bytes().all(|c| c.is_ascii_digit()) {
+        return number.into();
+    }
+
+    let mut out = EcoString::from(sign);
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            out.push_str(group_separator(lang));
+        }
+        out.push(c);
+    }
+
+    if let Some(frac) = frac {
+        out.push_str(decimal_mark(lang));
+        out.push_str(frac);
+    }
+
+    out
+}
+
+/// Spell out a whole number in words, if supported for the language.
+pub fn spell_out(number: i64, lang: Lang) -> Option<EcoString> {
+    match lang.as_str() {
+        "en" => Some(spell_out_english(number)),
+        _ => None,
+    }
+}
+
+/// Spell out a whole number in English words, like "forty-two".
+fn spell_out_english(number: i64) -> EcoString {
+    let ones = |n: u64| {
+        "zero one two three four five six seven eight nine ten eleven twelve \
+         thirteen fourteen fifteen sixteen seventeen eighteen nineteen"
+            .split(' ')
+            .nth(n as usize)
+            .unwrap_or_default()
+    };
+    let tens = |n: u64| {
+        "twenty thirty forty fifty sixty seventy eighty ninety"
+            .split(' ')
+            .nth((n as usize).saturating_sub(2))
+            .unwrap_or_default()
+    };
+    let scales =
+        ["quintillion", "quadrillion", "trillion", "billion", "million", "thousand", ""];
+
+    let magnitude = number.unsigned_abs();
+    if magnitude == 0 {
+        return ones(0).into();
+    }
+
+    let mut words: Vec<EcoString> = vec![];
+    if number < 0 {
+        words.push("minus".into());
+    }
+
+    // Go through the groups of three digits, from the most significant one.
+    for (i, scale) in scales.into_iter().enumerate() {
+        let group = magnitude / 1000_u64.pow(6 - i as u32) % 1000;
+        if group == 0 {
+            continue;
+        }
+
+        let (hundreds, rest) = (group / 100, group % 100);
+        if hundreds > 0 {
+            words.push(eco_format!("{} hundred", ones(hundreds)));
+        }
+
+        if rest >= 20 && rest % 10 > 0 {
+            words.push(eco_format!("{}-{}", tens(rest / 10), ones(rest % 10)));
+        } else if rest >= 20 {
+            words.push(tens(rest / 10).into());
+        } else if rest > 0 {
+            words.push(ones(rest).into());
+        }
+
+        if !scale.is_empty() {
+            words.push(scale.into());
+        }
+    }
+
+    words.join(" ").into()
+}
+
+/// Format a whole number as an ordinal, like "42nd", if supported for the
+/// language.
+pub fn ordinal(number: i64, lang: Lang) -> Option<EcoString> {
+    let digits = repr::format_int_with_base(number, 10);
+    let magnitude = number.unsigned_abs();
+    let suffix = match lang.as_str() {
+        "en" => match (magnitude % 10, magnitude % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        },
+        "fr" if magnitude == 1 => "er",
+        "fr" => "e",
+        "es" | "it" | "pt" => "º",
+        "de" | "da" | "nb" | "nn" | "fi" | "cs" | "sk" | "pl" | "sl" | "hu" | "tr" => ".",
+        _ => return None,
+    };
+    Some(eco_format!("{digits}{suffix}"))
+}
+
+/// The full name of a month (counting January as 1), if known for the
+/// language.
+///
+/// Returns `None` for English, whose names are built into the date
+/// formatting.
+pub fn month_name(lang: Lang, month: u8) -> Option<&'static str> {
+    let names = match lang.as_str() {
+        "de" => {
+            "Januar Februar März April Mai Juni \
+             Juli August September Oktober November Dezember"
+        }
+        "fr" => {
+            "janvier février mars avril mai juin \
+             juillet août septembre octobre novembre décembre"
+        }
+        "es" => {
+            "enero febrero marzo abril mayo junio \
+             julio agosto septiembre octubre noviembre diciembre"
+        }
+        "it" => {
+            "gennaio febbraio marzo aprile maggio giugno \
+             luglio agosto settembre ottobre novembre dicembre"
+        }
+        "nl" => {
+            "januari februari maart april mei juni \
+             juli augustus september oktober november december"
+        }
+        "pt" => {
+            "janeiro fevereiro março abril maio junho \
+             julho agosto setembro outubro novembro dezembro"
+        }
+        "sv" => {
+            "januari februari mars april maj juni \
+             juli augusti september oktober november december"
+        }
+        "da" => {
+            "januar februar marts april maj juni \
+             juli august september oktober november december"
+        }
+        "nb" | "nn" => {
+            "januar februar mars april mai juni \
+             juli august september oktober november desember"
+        }
+        _ => return None,
+    };
+    names.split(' ').nth(usize::from(month).checked_sub(1)?)
+}
+
+/// The full name of a weekday (counting Monday as 1), if known for the
+/// language.
+///
+/// Returns `None` for English, whose names are built into the date
+/// formatting.
+pub fn weekday_name(lang: Lang, weekday: u8) -> Option<&'static str> {
+    let names = match lang.as_str() {
+        "de" => "Montag Dienstag Mittwoch Donnerstag Freitag Samstag Sonntag",
+        "fr" => "lundi mardi mercredi jeudi vendredi samedi dimanche",
+        "es" => "lunes martes miércoles jueves viernes sábado domingo",
+        "it" => "lunedì martedì mercoledì giovedì venerdì sabato domenica",
+        "nl" => "maandag dinsdag woensdag donderdag vrijdag zaterdag zondag",
+        "pt" => {
+            "segunda-feira terça-feira quarta-feira quinta-feira \
+             sexta-feira sábado domingo"
+        }
+        "sv" => "måndag tisdag onsdag torsdag fredag lördag söndag",
+        "da" | "nb" => "mandag tirsdag onsdag torsdag fredag lørdag søndag",
+        "nn" => "måndag tysdag onsdag torsdag fredag laurdag søndag",
+        _ => return None,
+    };
+    names.split(' ').nth(usize::from(weekday).checked_sub(1)?)
+}
+
+/// The abbreviated name of a month (counting January as 1), if known for the
+/// language.
+///
+/// Returns `None` for English, whose names are built into the date
+/// formatting.
+pub fn short_month_name(lang: Lang, month: u8) -> Option<&'static str> {
+    let names = match lang.as_str() {
+        "de" => "Jan. Feb. März Apr. Mai Juni Juli Aug. Sept. Okt. Nov. Dez.",
+        "fr" => "janv. févr. mars avr. mai juin juil. août sept. oct. nov. déc.",
+        "es" => "ene feb mar abr may jun jul ago sept oct nov dic",
+        "it" => "gen feb mar apr mag giu lug ago set ott nov dic",
+        "nl" => "jan feb mrt apr mei jun jul aug sep okt nov dec",
+        "pt" => "jan fev mar abr mai jun jul ago set out nov dez",
+        "sv" => "jan. feb. mars apr. maj juni juli aug. sep. okt. nov. dec.",
+        "da" => "jan. feb. mar. apr. maj jun. jul. aug. sep. okt. nov. dec.",
+        "nb" | "nn" => "jan. feb. mar. apr. mai jun. jul. aug. sep. okt. nov. des.",
+        _ => return None,
+    };
+    names.split(' ').nth(usize::from(month).checked_sub(1)?)
+}
+
+/// The abbreviated name of a weekday (counting Monday as 1), if known for the
+/// language.
+///
+/// Returns `None` for English, whose names are built into the date
+/// formatting.
+pub fn short_weekday_name(lang: Lang, weekday: u8) -> Option<&'static str> {
+    let names = match lang.as_str() {
+        "de" => "Mo. Di. Mi. Do. Fr. Sa. So.",
+        "fr" => "lun. mar. mer. jeu. ven. sam. dim.",
+        "es" => "lun mar mié jue vie sáb dom",
+        "it" => "lun mar mer gio ven sab dom",
+        "nl" => "ma di wo do vr za zo",
+        "pt" => "seg ter qua qui sex sáb dom",
+        "sv" => "mån tis ons tors fre lör sön",
+        "da" => "man. tirs. ons. tors. fre. lør. søn.",
+        "nb" => "man. tir. ons. tor. fre. lør. søn.",
+        "nn" => "mån. tys. ons. tor. fre. lau. søn.",
+        _ => return None,
+    };
+    names.split(' ').nth(usize::from(weekday).checked_sub(1)?)
+}
@@ -17,11 +17,13 @@ mod auto;
 mod bool;
 mod bytes;
 mod datetime;
+mod decimal;
 mod duration;
 mod fields;
 mod float;
 mod func;
 mod int;
+pub mod locale;
 mod methods;
 mod module;
 mod none;
@@ -50,6 +52,7 @@ pub use self::cast::{
     cast, Cast, CastInfo, Container, FromValue, IntoResult, IntoValue, Never, Reflect,
 };
 pub use self::datetime::Datetime;
+pub use self::decimal::Decimal;
 pub use self::dict::{dict, Dict};
 pub use self::duration::Duration;
 pub use self::fields::fields_on;
@@ -80,12 +83,13 @@ use unicode_segmentation::UnicodeSegmentation;
 
 use self::func::Closure;
 use crate::diag::{
-    bail, error, warning, At, FileError, Hint, SourceDiagnostic, SourceResult, StrResult,
-    Trace, Tracepoint,
+    bail, error, warning, At, Code, FileError, Hint, SourceDiagnostic, SourceResult,
+    StrResult, Trace, Tracepoint,
 };
+use crate::doc::Lang;
 use crate::model::{
     Content, DelayedErrors, Introspector, Label, Locator, Recipe, ShowableSelector,
-    Styles, Transform, Unlabellable, Vt,
+    StyleChain, Styles, Transform, Unlabellable, Vt,
 };
 use crate::syntax::ast::{self, AstNode};
 use crate::syntax::{
@@ -139,6 +143,14 @@ pub fn eval(
         return Err(errors.into_iter().map(Into::into).collect());
     }
 
+    // In strict mode, constructs the parser had to guess are errors, too.
+    if vm.vt.tracer.is_strict() {
+        let heals = root.heals();
+        if !heals.is_empty() {
+            return Err(heals.into_iter().map(Into::into).collect());
+        }
+    }
+
     // Evaluate the module.
     let markup = root.cast::<ast::Markup>().unwrap();
     let output = markup.eval(&mut vm)?;
@@ -254,6 +266,10 @@ pub struct Vm<'a> {
     depth: usize,
     /// A span that is currently under inspection.
     inspected: Option<Span>,
+    /// The styles of the set rules in effect for the evaluated code.
+    styles: Styles,
+    /// Whether to trace definitions and reads of bindings.
+    bindings: bool,
 }
 
 impl<'a> Vm<'a> {
@@ -265,6 +281,7 @@ impl<'a> Vm<'a> {
         scopes: Scopes<'a>,
     ) -> Self {
         let inspected = file.and_then(|id| vt.tracer.inspected(id));
+        let bindings = vt.tracer.tracks_bindings();
         let items = vt.world.library().items.clone();
         Self {
             vt,
@@ -275,9 +292,32 @@ impl<'a> Vm<'a> {
             scopes,
             depth: 0,
             inspected,
+            styles: Styles::new(),
+            bindings,
         }
     }
 
+    /// The text language set by the set rules that are in effect for the
+    /// evaluated code, or else the library's default language.
+    ///
+    /// Only set rules in the same file and function as the evaluated code are
+    /// taken into account since styles are resolved during layout.
+    pub fn lang(&self) -> Lang {
+        let library = self.vt.world.library();
+        let chain = StyleChain::new(&library.styles).chain(&self.styles);
+        (self.items.lang)(chain)
+    }
+
+    /// Evaluate something with the styles of a set rule in effect.
+    fn with_styles<T>(&mut self, styles: &Styles, f: impl FnOnce(&mut Self) -> T) -> T {
+        let mut inner = styles.clone();
+        inner.apply(self.styles.clone());
+        let outer = std::mem::replace(&mut self.styles, inner);
+        let output = f(self);
+        self.styles = outer;
+        output
+    }
+
     /// Access the underlying world.
     pub fn world(&self) -> Tracked<'a, dyn World + 'a> {
         self.vt.world
@@ -307,7 +347,25 @@ impl<'a> Vm<'a> {
         if self.inspected == Some(var.span()) {
             self.vt.tracer.value(value.clone());
         }
-        self.scopes.top.define(var.get().clone(), value);
+        self.scopes.top.define_spanned(var.get().clone(), value, var.span());
+    }
+
+    /// Trace the definition of a let binding or imported item, so that it
+    /// can be reported if it is never read.
+    fn trace_binding(&mut self, var: ast::Ident) {
+        if self.bindings {
+            let top = self.depth == 0 && self.scopes.scopes.is_empty();
+            self.vt.tracer.bind(var.span(), top);
+        }
+    }
+
+    /// Trace a read of a variable.
+    fn trace_read(&mut self, var: &str) {
+        if self.bindings {
+            if let Some(span) = self.scopes.span(var) {
+                self.vt.tracer.read(span);
+            }
+        }
     }
 }
 
@@ -417,7 +475,8 @@ fn eval_markup<'a>(
                     break;
                 }
 
-                seq.push(eval_markup(vm, exprs)?.styled_with_map(styles))
+                let tail = vm.with_styles(&styles, |vm| eval_markup(vm, exprs))?;
+                seq.push(tail.styled_with_map(styles))
             }
             ast::Expr::Show(show) => {
                 let recipe = show.eval(vm)?;
@@ -436,7 +495,7 @@ fn eval_markup<'a>(
                         *elem = mem::take(elem).labelled(label);
                     }
                 }
-                value => seq.push(value.display().spanned(expr.span())),
+                value => seq.push(display(vm, value, expr.span())?),
             },
         }
 
@@ -536,10 +595,34 @@ trait ExprExt {
 
 impl ExprExt for ast::Expr<'_> {
     fn eval_display(&self, vm: &mut Vm) -> SourceResult<Content> {
-        Ok(self.eval(vm)?.display().spanned(self.span()))
+        let value = self.eval(vm)?;
+        display(vm, value, self.span())
     }
 }
 
+/// Display a value embedded into markup or math.
+///
+/// In strict mode, only values that are already textual may be displayed.
+/// Everything else must be converted explicitly, so that a stray `#12345` or
+/// a forgotten `.join()` doesn't silently end up in the document.
+fn display(vm: &mut Vm, value: Value, span: Span) -> SourceResult<Content> {
+    if vm.vt.tracer.is_strict()
+        && !matches!(
+            value,
+            Value::None
+                | Value::Str(_)
+                | Value::Symbol(_)
+                | Value::Content(_)
+                | Value::Module(_)
+        )
+    {
+        bail!(error!(span, "cannot implicitly display {} in strict mode", value.ty())
+            .with_hint("convert it to a string with `str` or `repr`"));
+    }
+
+    Ok(value.display().spanned(span))
+}
+
 impl Eval for ast::Text<'_> {
     type Output = Content;
 
@@ -751,7 +834,10 @@ impl Eval for ast::MathIdent<'_> {
 
     #[tracing::instrument(name = "MathIdent::eval", skip_all)]
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        vm.scopes.get_in_math(&self).cloned().at(self.span())
+        let value =
+            vm.scopes.get_in_math(&self).cloned().at(self.span()).code("E0101")?;
+        vm.trace_read(&self);
+        Ok(value)
     }
 }
 
@@ -830,7 +916,9 @@ impl Eval for ast::Ident<'_> {
 
     #[tracing::instrument(name = "Ident::eval", skip_all)]
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        vm.scopes.get(&self).cloned().at(self.span())
+        let value = vm.scopes.get(&self).cloned().at(self.span()).code("E0101")?;
+        vm.trace_read(&self);
+        Ok(value)
     }
 }
 
@@ -934,7 +1022,7 @@ fn eval_code<'a>(
                     break;
                 }
 
-                let tail = eval_code(vm, exprs)?.display();
+                let tail = vm.with_styles(&styles, |vm| eval_code(vm, exprs))?.display();
                 Value::Content(tail.styled_with_map(styles))
             }
             ast::Expr::Show(show) => {
@@ -1058,7 +1146,7 @@ impl Eval for ast::Unary<'_> {
             ast::UnOp::Neg => ops::neg(value),
             ast::UnOp::Not => ops::not(value),
         };
-        result.at(self.span())
+        result.at(self.span()).code("E0103")
     }
 }
 
@@ -1082,21 +1170,25 @@ impl Eval for ast::Binary<'_> {
             ast::BinOp::Geq => apply_binary_expr(self, vm, ops::geq),
             ast::BinOp::In => apply_binary_expr(self, vm, ops::in_),
             ast::BinOp::NotIn => apply_binary_expr(self, vm, ops::not_in),
-            ast::BinOp::Assign => apply_assignment(self, vm, |_, b| Ok(b)),
+            ast::BinOp::Assign => apply_assignment(self, vm, |_, b| StrResult::Ok(b)),
             ast::BinOp::AddAssign => apply_assignment(self, vm, ops::add),
             ast::BinOp::SubAssign => apply_assignment(self, vm, ops::sub),
             ast::BinOp::MulAssign => apply_assignment(self, vm, ops::mul),
             ast::BinOp::DivAssign => apply_assignment(self, vm, ops::div),
+            ast::BinOp::Pipe => apply_pipe(self, vm),
         }
     }
 }
 
 /// Apply a basic binary operation.
-fn apply_binary_expr(
+fn apply_binary_expr<E>(
     binary: ast::Binary,
     vm: &mut Vm,
-    op: fn(Value, Value) -> StrResult<Value>,
-) -> SourceResult<Value> {
+    op: fn(Value, Value) -> Result<Value, E>,
+) -> SourceResult<Value>
+where
+    Result<Value, E>: At<Value>,
+{
     let lhs = binary.lhs().eval(vm)?;
 
     // Short-circuit boolean operations.
@@ -1107,15 +1199,18 @@ fn apply_binary_expr(
     }
 
     let rhs = binary.rhs().eval(vm)?;
-    op(lhs, rhs).at(binary.span())
+    op(lhs, rhs).at(binary.span()).code("E0103")
 }
 
 /// Apply an assignment operation.
-fn apply_assignment(
+fn apply_assignment<E>(
     binary: ast::Binary,
     vm: &mut Vm,
-    op: fn(Value, Value) -> StrResult<Value>,
-) -> SourceResult<Value> {
+    op: fn(Value, Value) -> Result<Value, E>,
+) -> SourceResult<Value>
+where
+    Result<Value, E>: At<Value>,
+{
     let rhs = binary.rhs().eval(vm)?;
     let lhs = binary.lhs();
 
@@ -1131,10 +1226,29 @@ fn apply_assignment(
 
     let location = binary.lhs().access(vm)?;
     let lhs = std::mem::take(&mut *location);
-    *location = op(lhs, rhs).at(binary.span())?;
+    *location = op(lhs, rhs).at(binary.span()).code("E0103")?;
     Ok(Value::None)
 }
 
+/// Pass a value to a function with the pipe operator.
+fn apply_pipe(binary: ast::Binary, vm: &mut Vm) -> SourceResult<Value> {
+    let span = binary.span();
+    if vm.depth >= MAX_CALL_DEPTH {
+        bail!(span, "maximum function call depth exceeded");
+    }
+
+    let lhs = binary.lhs();
+    let value = lhs.eval(vm)?;
+    let rhs = binary.rhs();
+    let callee = rhs.eval(vm)?.cast::<Func>().at(rhs.span())?;
+
+    let mut args = Args::new(lhs.span(), [value]);
+    args.span = span;
+
+    let point = || Tracepoint::Call(callee.name().map(Into::into));
+    callee.call_vm(vm, args).trace(vm.world(), point, span)
+}
+
 impl Eval for ast::FieldAccess<'_> {
     type Output = Value;
 
@@ -1197,6 +1311,18 @@ impl Eval for ast::FuncCall<'_> {
                 return Ok(plugin.call(&field, bytes).at(span)?.into_value());
             }
 
+            // Types stand in for their constructors, so that function methods
+            // like `with` also work on them (e.g. `str.with(base: 16)`).
+            let target = match target {
+                Value::Type(ty)
+                    if ty.scope().get(&field).is_none()
+                        && Type::of::<Func>().scope().get(&field).is_some() =>
+                {
+                    Value::Func(ty.constructor().at(field_span)?)
+                }
+                target => target,
+            };
+
             // Prioritize associated functions on the value's type (i.e.,
             // methods) over its fields. A function call on a field is only
             // allowed for functions, types, modules (because they are scopes),
@@ -1362,6 +1488,12 @@ impl Eval for ast::Closure<'_> {
             visitor.finish()
         };
 
+        // Capturing a variable counts as reading it, even if the closure is
+        // never called.
+        for (name, _) in captured.iter() {
+            vm.trace_read(name);
+        }
+
         // Define the closure.
         let closure = Closure {
             node: self.to_untyped().clone(),
@@ -1536,6 +1668,10 @@ impl Eval for ast::LetBinding<'_> {
             }
         }
 
+        for ident in self.kind().idents() {
+            vm.trace_binding(ident);
+        }
+
         Ok(Value::None)
     }
 }
@@ -1818,8 +1954,17 @@ impl Eval for ast::ModuleImport<'_> {
                         }
 
                         vm.define(item.bound_name(), value.clone());
+                        vm.trace_binding(item.bound_name());
                     } else {
-                        errors.push(error!(original_ident.span(), "unresolved import"));
+                        let mut error =
+                            error!(original_ident.span(), "unresolved import");
+                        let names = scope.iter().map(|(name, _)| name.as_str());
+                        if let Some(similar) =
+                            crate::util::similar(&original_ident, names)
+                        {
+                            error.hint(eco_format!("did you mean `{similar}`?"));
+                        }
+                        errors.push(error);
                     }
                 }
                 if !errors.is_empty() {
@@ -2,9 +2,9 @@
 
 use std::cmp::Ordering;
 
-use ecow::eco_format;
+use ecow::{eco_format, EcoString};
 
-use crate::diag::{bail, StrResult};
+use crate::diag::{bail, HintedStrResult, HintedString, StrResult};
 use crate::eval::{format_str, item, IntoValue, Regex, Repr, Smart, Value};
 use crate::geom::{Align, Length, Numeric, Rel, Stroke};
 use Value::*;
@@ -12,7 +12,7 @@ use Value::*;
 /// Bail with a type mismatch error.
 macro_rules! mismatch {
     ($fmt:expr, $($value:expr),* $(,)?) => {
-        return Err(eco_format!($fmt, $($value.ty()),*))
+        return Err(eco_format!($fmt, $($value.ty()),*).into())
     };
 }
 
@@ -47,6 +47,7 @@ pub fn pos(value: Value) -> StrResult<Value> {
     Ok(match value {
         Int(v) => Int(v),
         Float(v) => Float(v),
+        Decimal(v) => Decimal(v),
         Length(v) => Length(v),
         Angle(v) => Angle(v),
         Ratio(v) => Ratio(v),
@@ -71,6 +72,7 @@ pub fn neg(value: Value) -> StrResult<Value> {
     Ok(match value {
         Int(v) => Int(v.checked_neg().ok_or_else(too_large)?),
         Float(v) => Float(-v),
+        Decimal(v) => Decimal(v.checked_neg().ok_or_else(too_large)?),
         Length(v) => Length(-v),
         Angle(v) => Angle(-v),
         Ratio(v) => Ratio(-v),
@@ -83,7 +85,7 @@ pub fn neg(value: Value) -> StrResult<Value> {
 }
 
 /// Compute the sum of two values.
-pub fn add(lhs: Value, rhs: Value) -> StrResult<Value> {
+pub fn add(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
     Ok(match (lhs, rhs) {
         (a, None) => a,
         (None, b) => b,
@@ -93,6 +95,10 @@ pub fn add(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Float(a), Int(b)) => Float(a + b as f64),
         (Float(a), Float(b)) => Float(a + b),
 
+        (Decimal(a), Decimal(b)) => Decimal(a.checked_add(b).ok_or_else(too_large)?),
+        (Decimal(a), Int(b)) => Decimal(a.checked_add(b.into()).ok_or_else(too_large)?),
+        (Int(a), Decimal(b)) => Decimal(b.checked_add(a.into()).ok_or_else(too_large)?),
+
         (Angle(a), Angle(b)) => Angle(a + b),
 
         (Length(a), Length(b)) => Length(a + b),
@@ -155,18 +161,27 @@ pub fn add(lhs: Value, rhs: Value) -> StrResult<Value> {
             mismatch!("cannot add {} and {}", a, b);
         }
 
-        (a, b) => mismatch!("cannot add {} and {}", a, b),
+        (a, b) => {
+            let message = eco_format!("cannot add {} and {}", a.ty(), b.ty());
+            return Err(unit_hint(message, &a, &b));
+        }
     })
 }
 
 /// Compute the difference of two values.
-pub fn sub(lhs: Value, rhs: Value) -> StrResult<Value> {
+pub fn sub(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
     Ok(match (lhs, rhs) {
         (Int(a), Int(b)) => Int(a.checked_sub(b).ok_or_else(too_large)?),
         (Int(a), Float(b)) => Float(a as f64 - b),
         (Float(a), Int(b)) => Float(a - b as f64),
         (Float(a), Float(b)) => Float(a - b),
 
+        (Decimal(a), Decimal(b)) => Decimal(a.checked_sub(b).ok_or_else(too_large)?),
+        (Decimal(a), Int(b)) => Decimal(a.checked_sub(b.into()).ok_or_else(too_large)?),
+        (Int(a), Decimal(b)) => {
+            Decimal(super::Decimal::from(a).checked_sub(b).ok_or_else(too_large)?)
+        }
+
         (Angle(a), Angle(b)) => Angle(a - b),
 
         (Length(a), Length(b)) => Length(a - b),
@@ -187,7 +202,10 @@ pub fn sub(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Datetime(a), Duration(b)) => Datetime(a - b),
         (Datetime(a), Datetime(b)) => Duration((a - b)?),
 
-        (a, b) => mismatch!("cannot subtract {1} from {0}", a, b),
+        (a, b) => {
+            let message = eco_format!("cannot subtract {} from {}", b.ty(), a.ty());
+            return Err(unit_hint(message, &a, &b));
+        }
     })
 }
 
@@ -199,6 +217,10 @@ pub fn mul(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Float(a), Int(b)) => Float(a * b as f64),
         (Float(a), Float(b)) => Float(a * b),
 
+        (Decimal(a), Decimal(b)) => Decimal(a.checked_mul(b).ok_or_else(too_large)?),
+        (Decimal(a), Int(b)) => Decimal(a.checked_mul(b.into()).ok_or_else(too_large)?),
+        (Int(a), Decimal(b)) => Decimal(b.checked_mul(a.into()).ok_or_else(too_large)?),
+
         (Length(a), Int(b)) => Length(a * b as f64),
         (Length(a), Float(b)) => Length(a * b),
         (Length(a), Ratio(b)) => Length(a * b.get()),
@@ -261,6 +283,12 @@ pub fn div(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Float(a), Int(b)) => Float(a / b as f64),
         (Float(a), Float(b)) => Float(a / b),
 
+        (Decimal(a), Decimal(b)) => Decimal(a.checked_div(b).ok_or_else(too_large)?),
+        (Decimal(a), Int(b)) => Decimal(a.checked_div(b.into()).ok_or_else(too_large)?),
+        (Int(a), Decimal(b)) => {
+            Decimal(super::Decimal::from(a).checked_div(b).ok_or_else(too_large)?)
+        }
+
         (Length(a), Int(b)) => Length(a / b as f64),
         (Length(a), Float(b)) => Length(a / b),
         (Length(a), Length(b)) => Float(try_div_length(a, b)?),
@@ -298,6 +326,7 @@ fn is_zero(v: &Value) -> bool {
     match *v {
         Int(v) => v == 0,
         Float(v) => v == 0.0,
+        Decimal(v) => v.is_zero(),
         Length(v) => v.is_zero(),
         Angle(v) => v.is_zero(),
         Ratio(v) => v.is_zero(),
@@ -356,7 +385,7 @@ pub fn neq(lhs: Value, rhs: Value) -> StrResult<Value> {
 macro_rules! comparison {
     ($name:ident, $op:tt, $($pat:tt)*) => {
         /// Compute how a value compares with another value.
-        pub fn $name(lhs: Value, rhs: Value) -> StrResult<Value> {
+        pub fn $name(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
             let ordering = compare(&lhs, &rhs)?;
             Ok(Bool(matches!(ordering, $($pat)*)))
         }
@@ -377,6 +406,7 @@ pub fn equal(lhs: &Value, rhs: &Value) -> bool {
         (Bool(a), Bool(b)) => a == b,
         (Int(a), Int(b)) => a == b,
         (Float(a), Float(b)) => a == b,
+        (Decimal(a), Decimal(b)) => a == b,
         (Length(a), Length(b)) => a == b,
         (Angle(a), Angle(b)) => a == b,
         (Ratio(a), Ratio(b)) => a == b,
@@ -403,6 +433,8 @@ pub fn equal(lhs: &Value, rhs: &Value) -> bool {
         // Some technically different things should compare equal.
         (&Int(a), &Float(b)) => a as f64 == b,
         (&Float(a), &Int(b)) => a == b as f64,
+        (&Decimal(a), &Int(b)) => a == b.into(),
+        (&Int(a), &Decimal(b)) => b == a.into(),
         (&Length(a), &Relative(b)) => a == b.abs && b.rel.is_zero(),
         (&Ratio(a), &Relative(b)) => a == b.rel && b.abs.is_zero(),
         (&Relative(a), &Length(b)) => a.abs == b && a.rel.is_zero(),
@@ -417,11 +449,12 @@ pub fn equal(lhs: &Value, rhs: &Value) -> bool {
 }
 
 /// Compare two values.
-pub fn compare(lhs: &Value, rhs: &Value) -> StrResult<Ordering> {
+pub fn compare(lhs: &Value, rhs: &Value) -> HintedStrResult<Ordering> {
     Ok(match (lhs, rhs) {
         (Bool(a), Bool(b)) => a.cmp(b),
         (Int(a), Int(b)) => a.cmp(b),
         (Float(a), Float(b)) => try_cmp_values(a, b)?,
+        (Decimal(a), Decimal(b)) => a.cmp(b),
         (Length(a), Length(b)) => try_cmp_values(a, b)?,
         (Angle(a), Angle(b)) => a.cmp(b),
         (Ratio(a), Ratio(b)) => a.cmp(b),
@@ -433,6 +466,8 @@ pub fn compare(lhs: &Value, rhs: &Value) -> StrResult<Ordering> {
         // Some technically different things should be comparable.
         (Int(a), Float(b)) => try_cmp_values(&(*a as f64), b)?,
         (Float(a), Int(b)) => try_cmp_values(a, &(*b as f64))?,
+        (Decimal(a), Int(b)) => a.cmp(&(*b).into()),
+        (Int(a), Decimal(b)) => super::Decimal::from(*a).cmp(b),
         (Length(a), Relative(b)) if b.rel.is_zero() => try_cmp_values(a, &b.abs)?,
         (Ratio(a), Relative(b)) if b.abs.is_zero() => a.cmp(&b.rel),
         (Relative(a), Length(b)) if a.rel.is_zero() => try_cmp_values(&a.abs, b)?,
@@ -441,10 +476,28 @@ pub fn compare(lhs: &Value, rhs: &Value) -> StrResult<Ordering> {
         (Duration(a), Duration(b)) => a.cmp(b),
         (Datetime(a), Datetime(b)) => try_cmp_datetimes(a, b)?,
 
-        _ => mismatch!("cannot compare {} and {}", lhs, rhs),
+        _ => {
+            let message = eco_format!("cannot compare {} and {}", lhs.ty(), rhs.ty());
+            return Err(unit_hint(message, lhs, rhs));
+        }
     })
 }
 
+/// Create a type mismatch error. If a length was combined with a bare number,
+/// the error hints that the number needs a unit.
+fn unit_hint(message: EcoString, lhs: &Value, rhs: &Value) -> HintedString {
+    let mut error = HintedString::from(message);
+    if let (Length(_), number @ (Int(_) | Float(_)))
+    | (number @ (Int(_) | Float(_)), Length(_)) = (lhs, rhs)
+    {
+        error.hints.push(eco_format!(
+            "a length needs a unit - did you mean {}pt?",
+            number.repr()
+        ));
+    }
+    error
+}
+
 /// Try to compare two values.
 fn try_cmp_values<T: PartialOrd + Repr>(a: &T, b: &T) -> StrResult<Ordering> {
     a.partial_cmp(b)
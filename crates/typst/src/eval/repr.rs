@@ -59,6 +59,28 @@ pub fn format_float(mut value: f64, precision: Option<u8>, suffix: &str) -> EcoS
     }
 }
 
+/// Format a float for display, with exactly `digits` decimal places if given.
+///
+/// In contrast to [`format_float`], this pads with trailing zeros so that
+/// numbers formatted with the same precision line up.
+pub fn display_float(value: f64, digits: Option<u8>, suffix: &str) -> EcoString {
+    let Some(digits) = digits else {
+        return format_float(value, None, suffix);
+    };
+
+    if value.is_nan() {
+        return "NaN".into();
+    }
+
+    let formatted = eco_format!("{:.*}", usize::from(digits), value.abs());
+    let is_zero = formatted.bytes().all(|c| matches!(c, b'0' | b'.'));
+    if value.is_sign_negative() && !is_zero {
+        eco_format!("{}{}{}", MINUS_SIGN, formatted, suffix)
+    } else {
+        eco_format!("{}{}", formatted, suffix)
+    }
+}
+
 /// Format pieces separated with commas and a final "and" or "or".
 pub fn separated_list(pieces: &[impl AsRef<str>], last: &str) -> String {
     let mut buf = String::new();
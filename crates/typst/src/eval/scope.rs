@@ -6,9 +6,11 @@ use indexmap::IndexMap;
 
 use crate::diag::{bail, HintedStrResult, HintedString, StrResult};
 use crate::eval::{
-    Func, IntoValue, Library, Module, NativeFunc, NativeFuncData, NativeType, Type, Value,
+    repr, Func, IntoValue, Library, Module, NativeFunc, NativeFuncData, NativeType, Type,
+    Value,
 };
 use crate::model::{Element, NativeElement};
+use crate::syntax::Span;
 
 /// A stack of scopes.
 #[derive(Debug, Default, Clone)]
@@ -45,7 +47,7 @@ impl<'a> Scopes<'a> {
             .chain(self.scopes.iter().rev())
             .chain(self.base.map(|base| base.global.scope()))
             .find_map(|scope| scope.get(var))
-            .ok_or_else(|| unknown_variable(var))
+            .ok_or_else(|| unknown_variable(var, self.base))
     }
 
     /// Try to access a variable immutably in math.
@@ -54,7 +56,17 @@ impl<'a> Scopes<'a> {
             .chain(self.scopes.iter().rev())
             .chain(self.base.map(|base| base.math.scope()))
             .find_map(|scope| scope.get(var))
-            .ok_or_else(|| unknown_variable(var))
+            .ok_or_else(|| unknown_variable(var, self.base))
+    }
+
+    /// The span of a variable's definition, if it was defined by evaluated
+    /// code.
+    pub fn span(&self, var: &str) -> Option<Span> {
+        std::iter::once(&self.top)
+            .chain(self.scopes.iter().rev())
+            .find_map(|scope| scope.map.get(var))
+            .map(|slot| slot.span)
+            .filter(|span| !span.is_detached())
     }
 
     /// Try to access a variable mutably.
@@ -65,7 +77,7 @@ impl<'a> Scopes<'a> {
             .ok_or_else(|| {
                 match self.base.and_then(|base| base.global.scope().get(var)) {
                     Some(_) => eco_format!("cannot mutate a constant: {}", var).into(),
-                    _ => unknown_variable(var),
+                    _ => unknown_variable(var, self.base),
                 }
             })?
     }
@@ -73,7 +85,7 @@ impl<'a> Scopes<'a> {
 
 /// The error message when a variable is not found.
 #[cold]
-fn unknown_variable(var: &str) -> HintedString {
+fn unknown_variable(var: &str, base: Option<&Library>) -> HintedString {
     let mut res = HintedString {
         message: eco_format!("unknown variable: {}", var),
         hints: vec![],
@@ -87,11 +99,40 @@ fn unknown_variable(var: &str) -> HintedString {
         res.hints.push(eco_format!(
             "if you meant to use subtraction, try adding spaces around the minus sign",
         ));
+    } else if let Some(base) = base {
+        let paths = namespaced_paths(var, base);
+        if !paths.is_empty() {
+            res.hints.push(eco_format!(
+                "did you mean {}?",
+                repr::separated_list(&paths, "or")
+            ));
+        }
     }
 
     res
 }
 
+/// Find where a name is defined in the standard library's namespaces, that
+/// is, in its modules (like `calc`) and the scopes of its element functions
+/// (like `list`).
+fn namespaced_paths(var: &str, base: &Library) -> Vec<EcoString> {
+    base.global
+        .scope()
+        .iter()
+        .filter_map(|(name, value)| {
+            let scope = match value {
+                // Math has its own syntax and symbols are modifiers rather
+                // than definitions, so neither helps in code.
+                Value::Module(module) if module.name() != "math" => module.scope(),
+                Value::Func(func) => func.scope()?,
+                _ => return None,
+            };
+            let found = scope.get(var)?;
+            (!matches!(found, Value::Symbol(_))).then(|| eco_format!("`{name}.{var}`"))
+        })
+        .collect()
+}
+
 /// A map from binding names to values.
 #[derive(Default, Clone)]
 pub struct Scope {
@@ -135,6 +176,18 @@ impl Scope {
             .insert(name, Slot::new(value.into_value(), Kind::Normal, self.category));
     }
 
+    /// Bind a value to a name that is defined at the given span.
+    pub fn define_spanned(
+        &mut self,
+        name: impl Into<EcoString>,
+        value: impl IntoValue,
+        span: Span,
+    ) {
+        let mut slot = Slot::new(value.into_value(), Kind::Normal, self.category);
+        slot.span = span;
+        self.map.insert(name.into(), slot);
+    }
+
     /// Define a native function through a Rust type that shadows the function.
     pub fn define_func<T: NativeFunc>(&mut self) {
         let data = T::data();
@@ -163,12 +216,17 @@ impl Scope {
         self.define(module.name().clone(), module);
     }
 
-    /// Define a captured, immutable binding.
-    pub fn define_captured(&mut self, var: impl Into<EcoString>, value: impl IntoValue) {
-        self.map.insert(
-            var.into(),
-            Slot::new(value.into_value(), Kind::Captured, self.category),
-        );
+    /// Define a captured, immutable binding of a variable that is defined at
+    /// the given span.
+    pub fn define_captured(
+        &mut self,
+        var: impl Into<EcoString>,
+        value: impl IntoValue,
+        span: Span,
+    ) {
+        let mut slot = Slot::new(value.into_value(), Kind::Captured, self.category);
+        slot.span = span;
+        self.map.insert(var.into(), slot);
     }
 
     /// Try to access a variable immutably.
@@ -224,6 +282,8 @@ struct Slot {
     kind: Kind,
     /// The category of the slot.
     category: Option<&'static str>,
+    /// Where the binding was defined, or detached for library definitions.
+    span: Span,
 }
 
 /// The different kinds of slots.
@@ -238,7 +298,7 @@ enum Kind {
 impl Slot {
     /// Create a new slot.
     fn new(value: Value, kind: Kind, category: Option<&'static str>) -> Self {
-        Self { value, kind, category }
+        Self { value, kind, category, span: Span::detached() }
     }
 
     /// Read the value.
@@ -8,9 +8,10 @@ use serde::{Deserialize, Serialize};
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::diag::{bail, At, SourceResult, StrResult};
+use crate::doc::Lang;
 use crate::eval::{
-    cast, dict, func, repr, scope, ty, Args, Array, Bytes, Dict, Func, IntoValue, Repr,
-    Type, Value, Version, Vm,
+    cast, dict, func, locale, repr, scope, ty, Args, Array, Bytes, Decimal, Dict, Func,
+    IntoValue, Repr, Type, Value, Version, Vm,
 };
 use crate::geom::Align;
 use crate::model::Label;
@@ -129,6 +130,8 @@ impl Str {
     /// - Integers are formatted in base 10. This can be overridden with the
     ///   optional `base` parameter.
     /// - Floats are formatted in base 10 and never in exponential notation.
+    /// - Decimals are formatted with all of their digits.
+    /// - Numbers follow the conventions of the language given as `lang`.
     /// - From labels the name is extracted.
     /// - Bytes are decoded as UTF-8.
     ///
@@ -141,6 +144,7 @@ impl Str {
     /// #str(4000, base: 16) \
     /// #str(2.7) \
     /// #str(1e8) \
+    /// #str(1234.5, lang: "de") \
     /// #str(<intro>)
     /// ```
     #[func(constructor)]
@@ -151,7 +155,20 @@ impl Str {
         #[named]
         #[default(Spanned::new(10, Span::detached()))]
         base: Spanned<i64>,
+        /// A language whose conventions for the decimal mark and digit
+        /// grouping to follow when converting a number in base 10. If omitted,
+        /// a period is used as the decimal mark and digits are not grouped.
+        #[named]
+        #[default]
+        lang: Option<Lang>,
     ) -> SourceResult<Str> {
+        let localize = |number: &str| -> Str {
+            match lang {
+                Some(lang) => locale::format_number(number, lang).into(),
+                None => number.into(),
+            }
+        };
+
         Ok(match value {
             ToStr::Str(s) => {
                 if base.v != 10 {
@@ -159,11 +176,22 @@ impl Str {
                 }
                 s
             }
+            ToStr::Number(s) => {
+                if base.v != 10 {
+                    bail!(base.span, "base is only supported for integers");
+                }
+                localize(&s)
+            }
             ToStr::Int(n) => {
                 if base.v < 2 || base.v > 36 {
                     bail!(base.span, "base must be between 2 and 36");
                 }
-                repr::format_int_with_base(n, base.v).into()
+                let digits = repr::format_int_with_base(n, base.v);
+                if base.v == 10 {
+                    localize(&digits)
+                } else {
+                    digits.into()
+                }
             }
         })
     }
@@ -755,6 +783,9 @@ cast! {
 pub enum ToStr {
     /// A string value ready to be used as-is.
     Str(Str),
+    /// A number formatted with a period as the decimal mark, which is
+    /// localized if a language is given.
+    Number(Str),
     /// An integer about to be formatted in a given base.
     Int(i64),
 }
@@ -762,7 +793,8 @@ pub enum ToStr {
 cast! {
     ToStr,
     v: i64 => Self::Int(v),
-    v: f64 => Self::Str(repr::format_float(v, None, "").into()),
+    v: f64 => Self::Number(repr::format_float(v, None, "").into()),
+    v: Decimal => Self::Number(v.display(None, None, None, None)),
     v: Version => Self::Str(format_str!("{}", v)),
     v: Bytes => Self::Str(
         std::str::from_utf8(&v)
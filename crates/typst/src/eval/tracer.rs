@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use ecow::EcoVec;
+use indexmap::IndexMap;
 
 use crate::diag::SourceDiagnostic;
 use crate::eval::Value;
@@ -14,6 +15,10 @@ pub struct Tracer {
     values: EcoVec<Value>,
     warnings: EcoVec<SourceDiagnostic>,
     warnings_set: HashSet<u128>,
+    strict: bool,
+    track_bindings: bool,
+    bindings: IndexMap<Span, bool>,
+    reads: HashSet<Span>,
 }
 
 impl Tracer {
@@ -31,6 +36,30 @@ impl Tracer {
         self.inspected = Some(span);
     }
 
+    /// Compile strictly: Reject constructs that the parser had to guess and
+    /// values that would be silently converted to text.
+    pub fn strict(&mut self) {
+        self.strict = true;
+    }
+
+    /// Track which bindings are defined and read during evaluation, so that
+    /// unused ones can be found with `unused_bindings` afterwards.
+    pub fn track_bindings(&mut self) {
+        self.track_bindings = true;
+    }
+
+    /// The spans of the names of let bindings and imported items that were
+    /// defined but never read, in definition order. Each span comes with
+    /// whether it was defined at the top level of a file, where other files
+    /// may still import it.
+    pub fn unused_bindings(&self) -> Vec<(Span, bool)> {
+        self.bindings
+            .iter()
+            .filter(|(span, _)| !self.reads.contains(span))
+            .map(|(&span, &top)| (span, top))
+            .collect()
+    }
+
     /// Get the values for the inspeted span.
     pub fn values(self) -> EcoVec<Value> {
         self.values
@@ -53,6 +82,26 @@ impl Tracer {
         }
     }
 
+    /// Whether the compilation is strict.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Whether definitions and reads of bindings are tracked.
+    pub fn tracks_bindings(&self) -> bool {
+        self.track_bindings
+    }
+
+    /// Trace the definition of a binding whose name is at the given span.
+    pub fn bind(&mut self, span: Span, top: bool) {
+        self.bindings.entry(span).or_insert(top);
+    }
+
+    /// Trace a read of the binding whose name is at the given span.
+    pub fn read(&mut self, span: Span) {
+        self.reads.insert(span);
+    }
+
     /// Trace a value for the span.
     pub fn value(&mut self, v: Value) {
         if self.values.len() < Self::MAX_VALUES {
@@ -12,9 +12,9 @@ use siphasher::sip128::{Hasher128, SipHasher13};
 
 use crate::diag::StrResult;
 use crate::eval::{
-    fields, ops, repr, Args, Array, AutoValue, Bytes, CastInfo, Content, Dict, Duration,
-    FromValue, Func, IntoValue, Module, NativeType, NoneValue, Plugin, Reflect, Repr,
-    Scope, Str, Symbol, Type, Version,
+    fields, ops, repr, Args, Array, AutoValue, Bytes, CastInfo, Content, Decimal, Dict,
+    Duration, FromValue, Func, IntoValue, Module, NativeType, NoneValue, Plugin, Reflect,
+    Repr, Scope, Str, Symbol, Type, Version,
 };
 use crate::eval::{item, Datetime};
 use crate::geom::{Abs, Angle, Color, Em, Fr, Gradient, Length, Ratio, Rel};
@@ -35,6 +35,8 @@ pub enum Value {
     Int(i64),
     /// A floating-point number: `1.2`, `10e-4`.
     Float(f64),
+    /// An exact decimal number: `decimal("1.2")`.
+    Decimal(Decimal),
     /// A length: `12pt`, `3cm`, `1.5em`, `1em - 2pt`.
     Length(Length),
     /// An angle: `1.5rad`, `90deg`.
@@ -118,6 +120,7 @@ impl Value {
             Self::Bool(_) => Type::of::<bool>(),
             Self::Int(_) => Type::of::<i64>(),
             Self::Float(_) => Type::of::<f64>(),
+            Self::Decimal(_) => Type::of::<Decimal>(),
             Self::Length(_) => Type::of::<Length>(),
             Self::Angle(_) => Type::of::<Angle>(),
             Self::Ratio(_) => Type::of::<Ratio>(),
@@ -199,6 +202,7 @@ impl Value {
             Self::None => Content::empty(),
             Self::Int(v) => item!(text)(repr::format_int_with_base(v, 10)),
             Self::Float(v) => item!(text)(repr::format_float(v, None, "")),
+            Self::Decimal(v) => item!(text)(v.display(None, None, None, None).into()),
             Self::Str(v) => item!(text)(v.into()),
             Self::Version(v) => item!(text)(eco_format!("{v}")),
             Self::Symbol(v) => item!(text)(v.get().into()),
@@ -226,6 +230,7 @@ impl Debug for Value {
             Self::Bool(v) => Debug::fmt(v, f),
             Self::Int(v) => Debug::fmt(v, f),
             Self::Float(v) => Debug::fmt(v, f),
+            Self::Decimal(v) => Debug::fmt(v, f),
             Self::Length(v) => Debug::fmt(v, f),
             Self::Angle(v) => Debug::fmt(v, f),
             Self::Ratio(v) => Debug::fmt(v, f),
@@ -262,6 +267,7 @@ impl Repr for Value {
             Self::Bool(v) => v.repr(),
             Self::Int(v) => v.repr(),
             Self::Float(v) => v.repr(),
+            Self::Decimal(v) => v.repr(),
             Self::Length(v) => v.repr(),
             Self::Angle(v) => v.repr(),
             Self::Ratio(v) => v.repr(),
@@ -311,6 +317,7 @@ impl Hash for Value {
             Self::Bool(v) => v.hash(state),
             Self::Int(v) => v.hash(state),
             Self::Float(v) => v.to_bits().hash(state),
+            Self::Decimal(v) => v.hash(state),
             Self::Length(v) => v.hash(state),
             Self::Angle(v) => v.hash(state),
             Self::Ratio(v) => v.hash(state),
@@ -619,6 +626,7 @@ macro_rules! primitive {
 primitive! { bool: "boolean", Bool }
 primitive! { i64: "integer", Int }
 primitive! { f64: "float", Float, Int(v) => v as f64 }
+primitive! { Decimal: "decimal", Decimal }
 primitive! { Length: "length", Length }
 primitive! { Angle: "angle", Angle }
 primitive! { Ratio: "ratio", Ratio }
@@ -104,6 +104,20 @@ impl Font {
             .map(|units| self.to_em(units))
     }
 
+    /// The OpenType layout features the font supports, like `kern` or `smcp`.
+    pub fn features(&self) -> Vec<String> {
+        let tables = self.0.ttf.tables();
+        let mut features: Vec<_> = [tables.gsub, tables.gpos]
+            .into_iter()
+            .flatten()
+            .flat_map(|table| table.features)
+            .map(|feature| feature.tag.to_string())
+            .collect();
+        features.sort();
+        features.dedup();
+        features
+    }
+
     /// Lookup a name by id.
     pub fn find_name(&self, id: u16) -> Option<String> {
         find_name(&self.0.ttf, id)
@@ -1,4 +1,5 @@
 use super::*;
+use crate::eval::Cast;
 
 /// An absolute length.
 #[derive(Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -226,7 +227,7 @@ cast! {
 }
 
 /// Different units of absolute measurement.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum AbsUnit {
     /// Points.
     Pt,
@@ -248,6 +249,16 @@ impl AbsUnit {
             AbsUnit::In => 72.0,
         }
     }
+
+    /// The suffix with which lengths in this unit are written.
+    pub fn suffix(self) -> &'static str {
+        match self {
+            AbsUnit::Pt => "pt",
+            AbsUnit::Mm => "mm",
+            AbsUnit::Cm => "cm",
+            AbsUnit::In => "in",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 use super::*;
 use crate::diag::{At, Hint, SourceResult};
+use crate::eval::repr::display_float;
+use crate::eval::Str;
 use crate::syntax::Span;
 
 /// A size or distance, possibly expressed with contextual units.
@@ -124,6 +126,46 @@ impl Length {
         self.ensure_that_em_is_zero(span, "inches")?;
         Ok(self.abs.to_inches())
     }
+
+    /// Formats this length for display in the document.
+    ///
+    /// The absolute part of the length is expressed in the given `unit` and
+    /// the font-relative part, if any, in `em`. Both are rounded to the given
+    /// number of `digits` and padded with trailing zeros. If no `digits` are
+    /// given, they are rounded to two decimal places without padding, like in
+    /// the length's representation.
+    ///
+    /// ```example
+    /// #(1in).display(unit: "cm") \
+    /// #(2.54cm).display(unit: "mm", digits: 1) \
+    /// #(12pt + 1.5em).display()
+    /// ```
+    #[func]
+    pub fn display(
+        &self,
+        /// The unit in which to express the absolute part of the length.
+        #[named]
+        #[default(AbsUnit::Pt)]
+        unit: AbsUnit,
+        /// The number of decimal places to display.
+        #[named]
+        #[default]
+        digits: Option<u8>,
+    ) -> Str {
+        let format = |value, suffix| match digits {
+            Some(_) => display_float(value, digits, suffix),
+            None => format_float(value, Some(2), suffix),
+        };
+
+        let abs = format(self.abs.to_unit(unit), unit.suffix());
+        let em = format(self.em.get(), "em");
+        match (self.abs.is_zero(), self.em.is_zero()) {
+            (false, false) => eco_format!("{abs} + {em}"),
+            (true, false) => em,
+            (_, true) => abs,
+        }
+        .into()
+    }
 }
 
 impl Debug for Length {
@@ -24,6 +24,7 @@ mod scalar;
 mod shape;
 mod sides;
 mod size;
+mod spot;
 mod stroke;
 mod transform;
 
@@ -51,6 +52,7 @@ pub use self::scalar::Scalar;
 pub use self::shape::{Geometry, Shape};
 pub use self::sides::{Side, Sides};
 pub use self::size::Size;
+pub use self::spot::SpotColor;
 pub use self::stroke::{DashLength, DashPattern, FixedStroke, LineCap, LineJoin, Stroke};
 pub use self::transform::Transform;
 
@@ -7,6 +7,8 @@ pub enum Paint {
     Solid(Color),
     /// A gradient.
     Gradient(Gradient),
+    /// A named spot color.
+    Spot(SpotColor),
 }
 
 impl Paint {
@@ -15,6 +17,7 @@ impl Paint {
         match self {
             Self::Solid(color) => *color,
             Self::Gradient(_) => panic!("expected solid color"),
+            Self::Spot(spot) => spot.fallback(),
         }
     }
 
@@ -28,6 +31,7 @@ impl Paint {
             Self::Gradient(gradient) => {
                 Self::Gradient(gradient.clone().with_relative(Relative::Parent))
             }
+            Self::Spot(spot) => Self::Spot(spot.clone()),
         }
     }
 }
@@ -37,6 +41,7 @@ impl Debug for Paint {
         match self {
             Self::Solid(v) => v.fmt(f),
             Self::Gradient(v) => v.fmt(f),
+            Self::Spot(v) => v.fmt(f),
         }
     }
 }
@@ -46,6 +51,7 @@ impl Repr for Paint {
         match self {
             Self::Solid(color) => color.repr(),
             Self::Gradient(gradient) => gradient.repr(),
+            Self::Spot(spot) => spot.repr(),
         }
     }
 }
@@ -62,12 +68,20 @@ impl From<Gradient> for Paint {
     }
 }
 
+impl From<SpotColor> for Paint {
+    fn from(spot: SpotColor) -> Self {
+        Self::Spot(spot)
+    }
+}
+
 cast! {
     Paint,
     self => match self {
         Self::Solid(color) => Value::Color(color),
         Self::Gradient(gradient) => Value::Gradient(gradient),
+        Self::Spot(spot) => Value::dynamic(spot),
     },
     color: Color => Self::Solid(color),
     gradient: Gradient => Self::Gradient(gradient),
+    spot: SpotColor => Self::Spot(spot),
 }
@@ -0,0 +1,77 @@
+use super::*;
+
+/// A named spot color for print.
+///
+/// Spot colors are printed with a premixed ink instead of being composed from
+/// the process colors. Print workflows identify the ink by its name, so it
+/// should exactly match the name in the ink library of your print shop.
+///
+/// When exporting to PDF, a spot color is written as a separation color space.
+/// Everywhere else, for example in PNG or SVG export, the fallback color is
+/// shown instead. The fallback is also what PDF viewers display on screen.
+///
+/// # Example
+/// ```example
+/// #let brand = spot(
+///   "PANTONE 300 C",
+///   cmyk(100%, 44%, 0%, 0%),
+/// )
+///
+/// #rect(fill: brand)
+/// #text(fill: brand)[Brand color]
+/// ```
+#[ty(scope, name = "spot")]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SpotColor {
+    /// The name of the ink.
+    name: EcoString,
+    /// The color to use when spot colors are not supported.
+    fallback: Color,
+}
+
+impl SpotColor {
+    /// Create a new spot color.
+    pub fn new(name: EcoString, fallback: Color) -> Self {
+        Self { name, fallback }
+    }
+}
+
+#[scope]
+impl SpotColor {
+    /// Create a spot color.
+    #[func(constructor)]
+    pub fn construct(
+        /// The name of the ink, like `{"PANTONE 300 C"}`.
+        name: EcoString,
+        /// The color to show when spot colors are not supported. This should
+        /// approximate the ink as closely as possible.
+        fallback: Color,
+    ) -> StrResult<SpotColor> {
+        if name.is_empty() {
+            bail!("spot color name must not be empty");
+        }
+        Ok(Self::new(name, fallback))
+    }
+
+    /// The name of the ink.
+    #[func]
+    pub fn name(&self) -> EcoString {
+        self.name.clone()
+    }
+
+    /// The color that is shown when spot colors are not supported.
+    #[func]
+    pub fn fallback(&self) -> Color {
+        self.fallback
+    }
+}
+
+impl Repr for SpotColor {
+    fn repr(&self) -> EcoString {
+        eco_format!("spot({}, {})", self.name.repr(), self.fallback.repr())
+    }
+}
+
+cast! {
+    type SpotColor,
+}
@@ -6,6 +6,7 @@ use ecow::{eco_format, EcoString};
 use image::codecs::gif::GifDecoder;
 use image::codecs::jpeg::JpegDecoder;
 use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
 use image::io::Limits;
 use image::{guess_format, ImageDecoder, ImageResult};
 use typst_macros::Cast;
@@ -40,13 +41,22 @@ impl RasterImage {
         }
 
         let cursor = io::Cursor::new(&data);
-        let (dynamic, icc) = match format {
+        let (mut dynamic, icc) = match format {
             RasterFormat::Jpg => decode_with(JpegDecoder::new(cursor)),
             RasterFormat::Png => decode_with(PngDecoder::new(cursor)),
             RasterFormat::Gif => decode_with(GifDecoder::new(cursor)),
+            RasterFormat::Webp => decode_with(WebPDecoder::new(cursor)),
         }
         .map_err(format_image_error)?;
 
+        // Photos are often stored sideways with an EXIF tag that specifies
+        // how they should be rotated for display.
+        if format == RasterFormat::Jpg {
+            if let Some(orientation) = exif_orientation(&data) {
+                dynamic = apply_orientation(dynamic, orientation);
+            }
+        }
+
         Ok(Self(Arc::new(Repr { data, format, dynamic, icc })))
     }
 
@@ -98,6 +108,8 @@ pub enum RasterFormat {
     Jpg,
     /// Raster format that is typically used for short animated clips.
     Gif,
+    /// Raster format for photos and graphics on the web.
+    Webp,
 }
 
 impl RasterFormat {
@@ -113,6 +125,7 @@ impl From<RasterFormat> for image::ImageFormat {
             RasterFormat::Png => image::ImageFormat::Png,
             RasterFormat::Jpg => image::ImageFormat::Jpeg,
             RasterFormat::Gif => image::ImageFormat::Gif,
+            RasterFormat::Webp => image::ImageFormat::WebP,
         }
     }
 }
@@ -125,11 +138,96 @@ impl TryFrom<image::ImageFormat> for RasterFormat {
             image::ImageFormat::Png => RasterFormat::Png,
             image::ImageFormat::Jpeg => RasterFormat::Jpg,
             image::ImageFormat::Gif => RasterFormat::Gif,
+            image::ImageFormat::WebP => RasterFormat::Webp,
             _ => bail!("Format not yet supported."),
         })
     }
 }
 
+/// Read the EXIF orientation tag of a JPEG image, if any.
+fn exif_orientation(data: &[u8]) -> Option<u16> {
+    if !data.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+
+    // Walk through the segments until the EXIF data or the image data starts.
+    let mut i = 2;
+    while data.get(i) == Some(&0xFF) {
+        let marker = *data.get(i + 1)?;
+        if marker == 0xDA {
+            break;
+        }
+
+        let len = u16::from_be_bytes([*data.get(i + 2)?, *data.get(i + 3)?]) as usize;
+        let segment = data.get(i + 4..i + 2 + len)?;
+        if marker == 0xE1 {
+            if let Some(tiff) = segment.strip_prefix(b"Exif\0\0") {
+                return tiff_orientation(tiff);
+            }
+        }
+
+        i += 2 + len;
+    }
+
+    None
+}
+
+/// Find the orientation tag in the first directory of TIFF-structured EXIF
+/// data.
+fn tiff_orientation(tiff: &[u8]) -> Option<u16> {
+    let big_endian = match tiff.get(..2)? {
+        b"MM" => true,
+        b"II" => false,
+        _ => return None,
+    };
+
+    let u16_at = |at: usize| {
+        let bytes = tiff.get(at..at + 2)?.try_into().ok()?;
+        Some(if big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    };
+
+    let u32_at = |at: usize| {
+        let bytes = tiff.get(at..at + 4)?.try_into().ok()?;
+        Some(if big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    };
+
+    let ifd = u32_at(4)? as usize;
+    let count = u16_at(ifd)? as usize;
+    (0..count).find_map(|k| {
+        let entry = ifd + 2 + 12 * k;
+        if u16_at(entry)? == 0x0112 {
+            u16_at(entry + 8)
+        } else {
+            None
+        }
+    })
+}
+
+/// Rotate and flip an image as specified by an EXIF orientation.
+fn apply_orientation(
+    image: image::DynamicImage,
+    orientation: u16,
+) -> image::DynamicImage {
+    match orientation {
+        2 => image.fliph(),
+        3 => image.rotate180(),
+        4 => image.flipv(),
+        5 => image.rotate90().fliph(),
+        6 => image.rotate90(),
+        7 => image.rotate270().fliph(),
+        8 => image.rotate270(),
+        _ => image,
+    }
+}
+
 /// Format the user-facing raster graphic decoding error message.
 fn format_image_error(error: image::ImageError) -> EcoString {
     match error {
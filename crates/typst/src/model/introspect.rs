@@ -42,6 +42,14 @@ impl Location {
         self.variant = n;
         self
     }
+
+    /// Produce a location that is distinct from the locations of other scopes.
+    /// This is used to keep the locations of separately laid out documents
+    /// apart when they are merged.
+    pub fn scoped(mut self, scope: usize) -> Self {
+        self.hash = crate::util::hash128(&(self.hash, scope));
+        self
+    }
 }
 
 #[scope]
@@ -403,7 +411,11 @@ impl Introspector {
 
     /// Gets the page numbering for the given location, if any.
     pub fn page_numbering(&self, location: Location) -> Value {
-        let page = self.page(location);
+        self.page_numbering_of(self.page(location))
+    }
+
+    /// Gets the page numbering for the given page, if any.
+    pub fn page_numbering_of(&self, page: NonZeroUsize) -> Value {
         self.page_numberings.get(page.get() - 1).cloned().unwrap_or_default()
     }
 
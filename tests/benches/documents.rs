@@ -0,0 +1,200 @@
+//! Benchmarks of the compilation stages on representative documents.
+//!
+//! Run them with `cargo bench -p typst-tests --bench documents`. Memoized
+//! results are evicted before each iteration, so every stage does its full
+//! work instead of hitting comemo's caches.
+
+use comemo::{Prehashed, Track, Tracked};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use typst::diag::FileResult;
+use typst::eval::{Bytes, Datetime, Library, Route, Tracer};
+use typst::font::{Font, FontBook};
+use typst::geom::Color;
+use typst::syntax::{FileId, Source};
+use typst::World;
+
+/// The documents to benchmark, with their names.
+const DOCUMENTS: &[(&str, &str)] = &[
+    ("thesis", include_str!("thesis.typ")),
+    ("slides", include_str!("slides.typ")),
+    ("report", include_str!("report.typ")),
+];
+
+/// The fonts used by the documents.
+const FONTS: &[&[u8]] = &[
+    include_bytes!("../../assets/fonts/LinLibertine_R.ttf"),
+    include_bytes!("../../assets/fonts/LinLibertine_RB.ttf"),
+    include_bytes!("../../assets/fonts/LinLibertine_RI.ttf"),
+    include_bytes!("../../assets/fonts/NewCMMath-Regular.otf"),
+    include_bytes!("../../assets/fonts/IBMPlexSans-Regular.ttf"),
+    include_bytes!("../../assets/fonts/IBMPlexSans-Bold.ttf"),
+    include_bytes!("../../assets/fonts/Roboto-Regular.ttf"),
+];
+
+/// Plain paragraphs, whose layout is dominated by shaping and line breaking.
+const SHAPING: &str = "#set page(width: 12cm, height: auto)\n#lorem(5000)";
+
+criterion_group!(
+    benches,
+    bench_tokenize,
+    bench_parse,
+    bench_eval,
+    bench_shape,
+    bench_layout,
+    bench_pdf,
+    bench_thumbnails,
+);
+criterion_main!(benches);
+
+fn bench_tokenize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tokenize");
+    for &(name, text) in DOCUMENTS {
+        group.bench_function(name, |b| b.iter(|| typst::syntax::tokenize(text)));
+    }
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for &(name, text) in DOCUMENTS {
+        group.bench_function(name, |b| b.iter(|| typst::syntax::parse(text)));
+    }
+}
+
+fn bench_eval(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eval");
+    for &(name, text) in DOCUMENTS {
+        let world = BenchWorld::new(text);
+        let route = Route::default();
+        let mut tracer = Tracer::new();
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || comemo::evict(0),
+                |_| {
+                    typst::eval::eval(
+                        world.track(),
+                        route.track(),
+                        tracer.track_mut(),
+                        &world.source,
+                    )
+                    .unwrap()
+                },
+                BatchSize::PerIteration,
+            )
+        });
+    }
+}
+
+fn bench_shape(c: &mut Criterion) {
+    let world = BenchWorld::new(SHAPING);
+    c.bench_function("shape", |b| bench_layout_of(b, &world));
+}
+
+fn bench_layout(c: &mut Criterion) {
+    let mut group = c.benchmark_group("layout");
+    for &(name, text) in DOCUMENTS {
+        let world = BenchWorld::new(text);
+        group.bench_function(name, |b| bench_layout_of(b, &world));
+    }
+}
+
+fn bench_pdf(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pdf");
+    for &(name, text) in DOCUMENTS {
+        let world = BenchWorld::new(text);
+        let document = typst::compile(&world, &mut Tracer::new()).unwrap();
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || comemo::evict(0),
+                |_| typst_pdf::pdf(&document, None, None),
+                BatchSize::PerIteration,
+            )
+        });
+    }
+}
+
+fn bench_thumbnails(c: &mut Criterion) {
+    let mut group = c.benchmark_group("thumbnails");
+    for &(name, text) in DOCUMENTS {
+        let world = BenchWorld::new(text);
+        let document = typst::compile(&world, &mut Tracer::new()).unwrap();
+        group.bench_function(name, |b| {
+            b.iter(|| typst_render::render_thumbnails(&document.pages, 256, Color::WHITE))
+        });
+    }
+}
+
+/// Benchmark the layout of an already evaluated document.
+fn bench_layout_of(b: &mut criterion::Bencher, world: &BenchWorld) {
+    let route = Route::default();
+    let mut tracer = Tracer::new();
+    let module = typst::eval::eval(
+        world.track(),
+        route.track(),
+        tracer.track_mut(),
+        &world.source,
+    )
+    .unwrap();
+    let content = module.content();
+    b.iter_batched(
+        || comemo::evict(0),
+        |_| typst::model::layout(world.track(), tracer.track_mut(), &content).unwrap(),
+        BatchSize::PerIteration,
+    );
+}
+
+struct BenchWorld {
+    library: Prehashed<Library>,
+    book: Prehashed<FontBook>,
+    fonts: Vec<Font>,
+    source: Source,
+}
+
+impl BenchWorld {
+    fn new(text: &str) -> Self {
+        let fonts: Vec<_> = FONTS
+            .iter()
+            .flat_map(|data| Font::iter(Bytes::from_static(data)))
+            .collect();
+
+        Self {
+            library: Prehashed::new(typst_library::build()),
+            book: Prehashed::new(FontBook::from_fonts(&fonts)),
+            fonts,
+            source: Source::detached(text),
+        }
+    }
+
+    fn track(&self) -> Tracked<dyn World> {
+        (self as &dyn World).track()
+    }
+}
+
+impl World for BenchWorld {
+    fn library(&self) -> &Prehashed<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &Prehashed<FontBook> {
+        &self.book
+    }
+
+    fn main(&self) -> Source {
+        self.source.clone()
+    }
+
+    fn source(&self, _: FileId) -> FileResult<Source> {
+        unimplemented!()
+    }
+
+    fn file(&self, _: FileId) -> FileResult<Bytes> {
+        unimplemented!()
+    }
+
+    fn font(&self, id: usize) -> Option<Font> {
+        Some(self.fonts[id].clone())
+    }
+
+    fn today(&self, _: Option<i64>) -> Option<Datetime> {
+        Some(Datetime::from_ymd(2023, 1, 1).unwrap())
+    }
+}
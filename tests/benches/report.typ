@@ -0,0 +1,46 @@
+// A data-heavy report with generated tables, computed summaries, and bar
+// charts drawn from the data.
+
+#set page(paper: "us-letter", margin: 2cm, numbering: "1")
+#set text(font: "Roboto", size: 9pt)
+#set table(stroke: 0.5pt + luma(180), inset: 4pt)
+
+#let regions = ("North", "East", "South", "West", "Central")
+#let months = ("Jan", "Feb", "Mar", "Apr", "May", "Jun",
+               "Jul", "Aug", "Sep", "Oct", "Nov", "Dec")
+
+// Deterministic pseudo-random sales figures.
+#let value(r, m, seed) = calc.rem(r * 7919 + m * 104729 + seed * 31, 997) + 3
+
+= Sales Report
+
+#for year in range(2015, 2024) [
+  == Fiscal Year #year
+
+  #let data = regions.enumerate().map(((r, region)) => {
+    (region, months.enumerate().map(((m, _)) => value(r, m, year)))
+  })
+
+  #table(
+    columns: (auto,) + (1fr,) * months.len() + (auto,),
+    align: (left,) + (right,) * (months.len() + 1),
+    [*Region*], ..months.map(m => [*#m*]), [*Total*],
+    ..data
+      .map(((region, values)) => (region,) + values.map(str) + (str(values.sum()),))
+      .flatten(),
+  )
+
+  #let totals = data.map(((_, values)) => values.sum())
+  #let best = calc.max(..totals)
+  The best region reached #best units, the average was
+  #calc.round(totals.sum() / totals.len(), digits: 1) units.
+
+  #stack(
+    dir: ltr,
+    spacing: 6pt,
+    ..regions.zip(totals).map(((region, total)) => align(bottom)[
+      #rect(width: 1.2cm, height: 3cm * total / best, fill: rgb("#2a9d8f"))
+      #text(7pt, region)
+    ]),
+  )
+]
@@ -0,0 +1,49 @@
+// A slide deck with a repeated background, large text, lists, and shapes.
+
+#set page(
+  width: 16cm,
+  height: 9cm,
+  margin: (x: 1cm, top: 1.5cm, bottom: 1cm),
+  background: rect(
+    width: 100%,
+    height: 100%,
+    fill: gradient.linear(luma(250), luma(220)),
+  ),
+  header: align(right, text(8pt)[Quarterly Review]),
+  numbering: "1 / 1",
+  number-align: right,
+)
+#set text(font: "IBM Plex Sans", size: 20pt)
+#show heading: set text(28pt, fill: rgb("#1d3557"))
+
+#let slide(title, body) = {
+  pagebreak(weak: true)
+  heading(title)
+  body
+}
+
+#align(center + horizon)[
+  #text(36pt, weight: "bold")[Quarterly Review] \
+  #text(16pt)[Engineering Department]
+]
+
+#for n in range(1, 31) {
+  slide[Topic #n][
+    - #lorem(6)
+    - #lorem(8)
+      - #lorem(5)
+    - #lorem(7)
+
+    #grid(
+      columns: (1fr, 1fr, 1fr),
+      gutter: 8pt,
+      ..range(3).map(i => rect(
+        width: 100%,
+        height: 1.2cm,
+        radius: 4pt,
+        fill: color.mix((rgb("#e63946"), 3 - i), (rgb("#457b9d"), i + 1)),
+        align(center + horizon, text(14pt, fill: white)[#(n * 10 + i)%]),
+      ))
+    )
+  ]
+}
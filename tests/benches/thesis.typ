@@ -0,0 +1,54 @@
+// A thesis with front matter, numbered chapters, math, figures, and
+// cross-references.
+
+#set document(title: "On the Layout of Long Documents", author: "Jane Doe")
+#set page(paper: "a4", margin: 2.5cm, numbering: "i")
+#set text(font: "Linux Libertine", size: 11pt, lang: "en")
+#set par(justify: true, leading: 0.65em)
+#set heading(numbering: "1.1")
+#set math.equation(numbering: "(1)")
+#show heading.where(level: 1): it => pagebreak(weak: true) + it
+
+#align(center)[
+  #v(4cm)
+  #text(24pt, weight: "bold")[On the Layout of Long Documents]
+  #v(1cm)
+  Jane Doe \
+  Institute of Typesetting
+]
+
+#pagebreak()
+#outline(indent: auto)
+
+#set page(numbering: "1")
+#counter(page).update(1)
+
+#for chapter in range(1, 7) [
+  #heading(level: 1)[Chapter #chapter] #label("chapter-" + str(chapter))
+  #lorem(180)
+
+  #for section in range(1, 4) [
+    == Section #chapter.#section
+    #lorem(120)
+
+    The energy of a particle with mass $m$ and momentum $p$ satisfies
+    #let energy = label("energy-" + str(chapter) + "-" + str(section))
+    $ E^2 = (m c^2)^2 + (p c)^2 $ #energy
+    As #ref(energy) shows, $E >= m c^2$ holds, where
+    $ integral_0^infinity e^(-x^2) dif x = sqrt(pi) / 2 quad "and"
+      sum_(k=1)^n k = (n (n + 1)) / 2. $
+
+    #figure(
+      table(
+        columns: 4,
+        [*Run*], [*Pages*], [*Time*], [*Memory*],
+        ..range(1, 6)
+          .map(i => (str(i), str(i * 17), str(i * 0.31) + "s", str(i * 12) + "MB"))
+          .flatten(),
+      ),
+      caption: [Measurements for section #chapter.#section.],
+    )
+
+    #lorem(90) See @chapter-1 for the overview.
+  ]
+]
@@ -20,6 +20,8 @@ main!(
     bench_layout,
     bench_compile,
     bench_render,
+    bench_pdf,
+    bench_svg,
 );
 
 fn bench_decode(iai: &mut Iai) {
@@ -93,6 +95,20 @@ fn bench_render(iai: &mut Iai) {
     iai.run(|| typst_render::render(&document.pages[0], 1.0, Color::WHITE))
 }
 
+fn bench_pdf(iai: &mut Iai) {
+    let world = BenchWorld::new();
+    let mut tracer = Tracer::new();
+    let document = typst::compile(&world, &mut tracer).unwrap();
+    iai.run(|| typst_pdf::pdf(&document, None, None))
+}
+
+fn bench_svg(iai: &mut Iai) {
+    let world = BenchWorld::new();
+    let mut tracer = Tracer::new();
+    let document = typst::compile(&world, &mut tracer).unwrap();
+    iai.run(|| typst_svg::svg(&document.pages[0]))
+}
+
 struct BenchWorld {
     library: Prehashed<Library>,
     book: Prehashed<FontBook>,
@@ -367,6 +367,7 @@ fn test(
     let mut line = 0;
     let mut compare_ref = None;
     let mut validate_hints = None;
+    let mut strict = None;
     let mut compare_ever = false;
     let mut rng = LinearShift::new();
 
@@ -396,6 +397,7 @@ fn test(
             for line in part.lines() {
                 compare_ref = get_flag_metadata(line, "Ref").or(compare_ref);
                 validate_hints = get_flag_metadata(line, "Hints").or(validate_hints);
+                strict = get_flag_metadata(line, "Strict").or(strict);
             }
         } else {
             let (part_ok, compare_here, part_frames) = test_part(
@@ -406,6 +408,7 @@ fn test(
                 i,
                 compare_ref.unwrap_or(true),
                 validate_hints.unwrap_or(true),
+                strict.unwrap_or(false),
                 line,
                 &mut rng,
             );
@@ -517,6 +520,7 @@ fn test_part(
     i: usize,
     compare_ref: bool,
     validate_hints: bool,
+    strict: bool,
     line: usize,
     rng: &mut LinearShift,
 ) -> (bool, bool, Vec<Frame>) {
@@ -531,6 +535,7 @@ fn test_part(
     let compare_ref = metadata.part_configuration.compare_ref.unwrap_or(compare_ref);
     let validate_hints =
         metadata.part_configuration.validate_hints.unwrap_or(validate_hints);
+    let strict = metadata.part_configuration.strict.unwrap_or(strict);
 
     ok &= test_spans(output, source.root());
     ok &= test_reparse(output, source.text(), i, rng);
@@ -546,6 +551,10 @@ fn test_part(
     }
 
     let mut tracer = Tracer::new();
+    if strict {
+        tracer.strict();
+    }
+
     let (mut frames, diagnostics) = match typst::compile(world, &mut tracer) {
         Ok(document) => (document.pages, tracer.warnings()),
         Err(errors) => {
@@ -590,6 +599,18 @@ fn test_part(
                     range: annotation.range.clone(),
                 });
             }
+
+            for related in &diagnostic.related {
+                if related.span.id() != Some(source.id()) {
+                    continue;
+                }
+
+                actual_diagnostics.insert(Annotation {
+                    kind: AnnotationKind::Related,
+                    message: related.v.clone(),
+                    range: world.range(related.span),
+                });
+            }
         }
 
         actual_diagnostics.insert(annotation);
@@ -648,6 +669,7 @@ fn print_annotation(
 struct TestConfiguration {
     compare_ref: Option<bool>,
     validate_hints: Option<bool>,
+    strict: Option<bool>,
 }
 
 struct TestPartMetadata {
@@ -667,11 +689,18 @@ enum AnnotationKind {
     Error,
     Warning,
     Hint,
+    Related,
 }
 
 impl AnnotationKind {
     fn iter() -> impl Iterator<Item = Self> {
-        [AnnotationKind::Error, AnnotationKind::Warning, AnnotationKind::Hint].into_iter()
+        [
+            AnnotationKind::Error,
+            AnnotationKind::Warning,
+            AnnotationKind::Hint,
+            AnnotationKind::Related,
+        ]
+        .into_iter()
     }
 
     fn as_str(self) -> &'static str {
@@ -679,6 +708,7 @@ impl AnnotationKind {
             AnnotationKind::Error => "Error",
             AnnotationKind::Warning => "Warning",
             AnnotationKind::Hint => "Hint",
+            AnnotationKind::Related => "Related",
         }
     }
 }
@@ -692,12 +722,14 @@ impl Display for AnnotationKind {
 fn parse_part_metadata(source: &Source) -> TestPartMetadata {
     let mut compare_ref = None;
     let mut validate_hints = None;
+    let mut strict = None;
     let mut annotations = HashSet::default();
 
     let lines: Vec<_> = source.text().lines().map(str::trim).collect();
     for (i, line) in lines.iter().enumerate() {
         compare_ref = get_flag_metadata(line, "Ref").or(compare_ref);
         validate_hints = get_flag_metadata(line, "Hints").or(validate_hints);
+        strict = get_flag_metadata(line, "Strict").or(strict);
 
         fn num(s: &mut Scanner) -> Option<isize> {
             let mut first = true;
@@ -740,7 +772,7 @@ fn parse_part_metadata(source: &Source) -> TestPartMetadata {
     }
 
     TestPartMetadata {
-        part_configuration: TestConfiguration { compare_ref, validate_hints },
+        part_configuration: TestConfiguration { compare_ref, validate_hints, strict },
         annotations,
     }
 }
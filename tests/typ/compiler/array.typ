@@ -182,6 +182,7 @@
 
 ---
 // Error: 20-22 unexpected argument
+// Related: 20-22 parameters declared here
 #(1, 2, 3).fold(0, () => none)
 
 ---
@@ -215,6 +216,8 @@
 #test((1,).join(), 1)
 #test(("a", "b", "c").join(), "abc")
 #test("(" + ("a", "b", "c").join(", ") + ")", "(a, b, c)")
+#test(("a", "b", "c").join(", ", lang: "de"), "a, b und c")
+#test(("a", "b").join(", ", last: " & ", lang: "de"), "a & b")
 
 ---
 // Error: 2-22 cannot join boolean with boolean
@@ -293,6 +296,16 @@
 #test(("Hello", "World", "Hi", "There").dedup(key: x => x.len()), ("Hello", "Hi"))
 #test(("Hello", "World", "Hi", "There").dedup(key: x => x.at(0)), ("Hello", "World", "There"))
 
+---
+// Test the `group-by` method.
+#test(().group-by(x => x), ())
+#test((1, 2, 3, 4, 5).group-by(x => calc.odd(x)), ((true, (1, 3, 5)), (false, (2, 4))))
+#test(
+  ("apple", "avocado", "banana", "cherry", "blueberry").group-by(x => x.first()),
+  (("a", ("apple", "avocado")), ("b", ("banana", "blueberry")), ("c", ("cherry",))),
+)
+#test((1, 1.0, 2).group-by(x => x).len(), 2)
+
 ---
 // Error: 9-26 unexpected argument: val
 #().zip(val: "applicable")
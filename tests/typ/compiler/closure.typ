@@ -119,6 +119,7 @@
   test(types(14%, 12pt), "[ratio, length]")
 
   // Error: 13-21 missing argument: y
+  // Related: -5:16--5:17 parameter declared here
   test(types("nope"), "[string, none]")
 }
 
@@ -128,6 +129,7 @@
   let f(x) = x + 1
 
   // Error: 8-13 unexpected argument
+  // Related: -4:8--4:11 parameters declared here
   f(1, "two", () => x)
 }
 
@@ -160,6 +162,7 @@
   test(greet("Typst", birthday: true), "Happy Birthday, Typst!")
 
   // Error: 23-35 unexpected argument: whatever
+  // Related: -9:12--9:35 parameters declared here
   test(greet("Typst", whatever: 10))
 }
 
@@ -83,3 +83,19 @@
 #test-repr(luma(20%).lighten(50%), luma(60%))
 #test-repr(luma(80%).darken(20%), luma(64%))
 #test-repr(luma(80%).negate(), luma(20%))
+
+---
+// Test looking up CSS named colors.
+// Ref: false
+#test(color("rebeccapurple"), rgb("#663399"))
+#test(color("LightSeaGreen"), rgb(32, 178, 170))
+#test(color("red"), rgb("#ff0000"))
+
+---
+// Error: 8-23 unknown color name: "rebeccapurpel"
+// Hint: 8-23 did you mean "rebeccapurple"?
+#color("rebeccapurpel")
+
+---
+// Error: 8-13 unknown color name: "foo"
+#color("foo")
@@ -39,3 +39,13 @@ Can not be used as @intro
 
 // Error: 1:20-1:26 cannot reference heading without numbering
 Can not be used as @intro
+
+---
+// Error: 2-5 unknown variable: sin
+// Hint: 2-5 did you mean `calc.sin`?
+#sin(1)
+
+---
+// Error: 2-6 unknown variable: item
+// Hint: 2-6 did you mean `list.item`, `enum.item`, or `terms.item`?
+#item[A]
@@ -197,6 +197,11 @@
 // Error: 23-35 unresolved import
 #import "module.typ": non_existing
 
+---
+// Error: 23-28 unresolved import
+// Hint: 23-28 did you mean `value`?
+#import "module.typ": vaule
+
 ---
 // Cyclic import of this very file.
 // Error: 9-23 cyclic import
@@ -75,6 +75,39 @@
 #test(5em.abs.cm(), 0.0)
 #test((5em + 6in).abs.inches(), 6.0)
 
+---
+// Test formatting lengths and floats for display.
+#test((12pt).display(), "12pt")
+#test((1in).display(unit: "cm"), "2.54cm")
+#test((2.54cm).display(unit: "mm", digits: 1), "25.4mm")
+#test((12pt + 1.5em).display(), "12pt + 1.5em")
+#test((-0.5em).display(digits: 2), "\u{2212}0.50em")
+#test(calc.pi.display(digits: 3), "3.142")
+#test((1.5).display(digits: 2), "1.50")
+#test((-0.001).display(digits: 2), "0.00")
+#test((1 / 8).display(), "0.125")
+#test((12345.678).display(lang: "de"), "12.345,678")
+#test((-1234.5).display(digits: 2, lang: "en"), "\u{2212}1,234.50")
+#test((1234.5).display(lang: "fr"), "1\u{202F}234,5")
+
+---
+// Test formatting integers for display.
+#test((1234567).display(), "1234567")
+#test((1234567).display(lang: "de"), "1.234.567")
+#test((-42).display(form: "spell-out"), "minus forty-two")
+#test((0).display(form: "spell-out"), "zero")
+#test((1002).display(form: "spell-out"), "one thousand two")
+#test((7300011).display(form: "spell-out"), "seven million three hundred thousand eleven")
+#test((11).display(form: "ordinal"), "11th")
+#test((22).display(form: "ordinal"), "22nd")
+#test((103).display(form: "ordinal"), "103rd")
+#test((1).display(form: "ordinal", lang: "fr"), "1er")
+#test((3).display(form: "ordinal", lang: "de"), "3.")
+
+---
+// Error: 2-45 cannot spell out numbers in language `de`
+#(42).display(form: "spell-out", lang: "de")
+
 ---
 // Error: 2-21 cannot convert a length with non-zero em units (`−6pt + 10.5em`) to pt
 // Hint: 2-21 use `length.abs.pt()` instead to ignore its em component
@@ -54,6 +54,21 @@
 // Error: 3-10 cannot add integer and string
 #(1 + "2", 40% - 1)
 
+---
+// Error: 3-10 cannot add length and integer
+// Hint: 3-10 a length needs a unit - did you mean 1pt?
+#(1pt + 1)
+
+---
+// Error: 3-12 cannot subtract float from length
+// Hint: 3-12 a length needs a unit - did you mean 2.5pt?
+#(1cm - 2.5)
+
+---
+// Error: 3-10 cannot compare integer and length
+// Hint: 3-10 a length needs a unit - did you mean 3pt?
+#(3 < 2cm)
+
 ---
 // Error: 15-23 cannot add integer and string
 #{ let x = 1; x += "2" }
@@ -124,3 +139,7 @@
 // (since then it doesn't resolve to the standard library version anymore).
 #let rect = ""
 #(rect = "hi")
+
+---
+// Error: 8-9 expected function, found integer
+#(1 >> 2)
@@ -206,6 +206,11 @@
 #test(50% < 40% + 0pt, false)
 #test(40% + 0pt < 50% + 0pt, true)
 #test(1em < 2em, true)
+#test(1cm > 8mm, true)
+#test(-1cm < 8mm, true)
+#test(calc.min(1cm, 8mm, 1in), 8mm)
+#test(calc.max(-1cm, -8mm), -8mm)
+#test(calc.abs(-2cm), 2cm)
 
 ---
 // Test assignment operators.
@@ -301,3 +306,20 @@
 #let inc2 = inc.with(y: 2)
 #test(inc2(2), 4)
 #test(inc2(2, y: 4), 6)
+
+// Types can be partially applied like their constructors.
+#let hex = str.with(base: 16)
+#test(hex(255), "ff")
+#test((1, 2, 3).map(str.with(base: 2)), ("1", "10", "11"))
+
+---
+// Test the pipe operator.
+#let double(x) = 2 * x
+#test(3 >> double, 6)
+#test(1 + 2 >> double >> double, 12)
+#test((3, 1, 2) >> array.sorted >> array.rev, (3, 2, 1))
+#test("abc" >> str.len, 3)
+#test(5 >> str, "5")
+#test((1, 2) >> (it => it.sum()), 3)
+#let x = 2 >> double
+#test(x, 4)
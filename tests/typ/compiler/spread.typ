@@ -119,5 +119,6 @@
   let f(..a, b, c, d) = none
 
   // Error: 4-10 missing argument: d
+  // Related: -4:20--4:21 parameter declared here
   f(1, 2)
 }
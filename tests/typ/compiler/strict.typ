@@ -0,0 +1,52 @@
+// Test strict compilation.
+// Ref: false
+// Strict: true
+
+---
+// Textual values are displayed as usual.
+#let name = "Typst"
+#name #sym.arrow #none #[content] #str(12345) $x^2$
+
+---
+// Error: 2-7 cannot implicitly display integer in strict mode
+// Hint: 2-7 convert it to a string with `str` or `repr`
+#12345
+
+---
+// Error: 9-16 cannot implicitly display integer in strict mode
+// Hint: 9-16 convert it to a string with `str` or `repr`
+Total: #(1 + 2)
+
+---
+// Error: 2-17 cannot implicitly display array in strict mode
+// Hint: 2-17 convert it to a string with `str` or `repr`
+#(1, 2).map(str)
+
+---
+// Error: 5-6 cannot implicitly display integer in strict mode
+// Hint: 5-6 convert it to a string with `str` or `repr`
+$x^#2$
+
+---
+// Balanced and escaped quotes and delimiters are fine.
+He said "hi" and \"bye. $\(a + b$ $[a, b)$
+
+---
+// Error: 2-3 unclosed delimiter `(`
+// Hint: 2-3 add a closing delimiter or escape this one with a backslash
+$(a + b$
+
+---
+// Error: 18-19 unclosed quote
+// Hint: 18-19 add a closing quote or escape this one with a backslash
+He said "hi" and "bye.
+
+---
+// A quote must be closed in the same paragraph.
+// Error: 1-2 unclosed quote
+// Hint: 1-2 add a closing quote or escape this one with a backslash
+// Error: 2:9-2:10 unclosed quote
+// Hint: 2:9-2:10 add a closing quote or escape this one with a backslash
+"Once upon a time.
+
+The end."
@@ -226,6 +226,25 @@
 // Error: 10-41 the return value is too large
 #calc.lcm(15486487489457, 4874879896543)
 
+---
+// Test the `random` function.
+#test(calc.random(42), calc.random(42))
+#test(calc.random(42, count: 3).first(), calc.random(42))
+#test(calc.random(1, count: 0), ())
+#test(calc.random(1) != calc.random(2), true)
+#for x in calc.random(-5, count: 100) {
+  test(type(x), float)
+  test(0 <= x and x < 1, true)
+}
+
+---
+// Test the `shuffled` method.
+#let numbers = range(20)
+#test(numbers.shuffled(3), numbers.shuffled(3))
+#test(numbers.shuffled(3).sorted(), numbers)
+#test(numbers.shuffled(3) != numbers.shuffled(4), true)
+#test(().shuffled(1), ())
+
 ---
 // Error: 10-12 expected at least one value
 #calc.min()
@@ -186,6 +186,11 @@
 #test(str(9223372036854775807, base: 36), "1y2p0ij32e8e7")
 #test(str(50.14), "50.14")
 #test(str(10 / 3).len() > 10, true)
+#test(str(1234567, lang: "de"), "1.234.567")
+#test(str(-1234.5, lang: "en"), "−1,234.5")
+#test(str(decimal("1234.5"), lang: "fr"), "1\u{202F}234,5")
+#test(str(1234, base: 16, lang: "de"), "4d2")
+#test(str("1234", lang: "de"), "1234")
 
 ---
 // Error: 6-8 expected integer, float, version, bytes, label, type, or string, found content
@@ -237,6 +242,35 @@
     .display("[year] [month repr:long] [day] [week_number] [weekday]"),
   "2023 April 29 17 Saturday",
 )
+#test(
+  datetime(year: 2023, month: 5, day: 1)
+    .display("[weekday], [day padding:none]. [month repr:long] [year]", lang: "de"),
+  "Montag, 1. Mai 2023",
+)
+#test(
+  datetime(year: 2023, month: 5, day: 1)
+    .display("[day] [month repr:short] [weekday repr:short]", lang: "fr"),
+  "01 mai lun.",
+)
+#test(
+  datetime(year: 2023, month: 9, day: 3)
+    .display("[weekday repr:short] [day padding:none] [month repr:short]", lang: "de"),
+  "So. 3 Sept.",
+)
+
+// The language defaults to the one of the surrounding set rules.
+#let date = datetime(year: 2023, month: 5, day: 1)
+#test(date.display("[weekday] [month repr:long]"), "Monday May")
+#[
+  #set text(lang: "es")
+  #test(date.display("[weekday] [month repr:long]"), "lunes mayo")
+  #test(date.display("[weekday] [month repr:long]", lang: "it"), "lunedì maggio")
+]
+#{
+  set text(lang: "nl")
+  test(date.display("[weekday repr:short] [month repr:short]"), "ma mei")
+}
+#test(date.display("[weekday] [month repr:long]"), "Monday May")
 
 // Test displaying of times
 #test(datetime(hour: 14, minute: 26, second: 50).display(), "14:26:50")
@@ -129,3 +129,50 @@
 ---
 // Error: 6-22 failed to parse XML (found closing tag 'data' instead of 'hello' in line 3)
 #xml("/files/bad.xml")
+
+---
+// Test reading and encoding Base64 data.
+#test(str(base64("/files/hello.b64")), read("/files/hello.txt"))
+#test(str(base64.decode("SGVsbG8h")), "Hello!")
+#test(str(base64.decode(bytes("SGVs bG8h\n"))), "Hello!")
+#test(base64.encode("Hello!"), "SGVsbG8h")
+#test(base64.encode(bytes((0, 255))), "AP8=")
+#test(base64.decode(base64.encode(read("/files/rhino.png", encoding: none))), read("/files/rhino.png", encoding: none))
+
+---
+// Error: 16-21 failed to decode Base64 (Invalid byte 35, offset 0.)
+#base64.decode("###")
+
+---
+// Test reading and encoding hex data.
+#test(str(hex("/files/hello.hex")), read("/files/hello.txt"))
+#test(str(hex.decode("48656C6c6f21")), "Hello!")
+#test(str(hex.decode(bytes("4865 6c6c\n6f21"))), "Hello!")
+#test(hex.encode("Hello!"), "48656c6c6f21")
+#test(hex.encode(bytes((0, 15, 255))), "000fff")
+#test(hex.decode(hex.encode(read("/files/rhino.png", encoding: none))), read("/files/rhino.png", encoding: none))
+
+---
+// Error: 13-18 failed to decode hex (odd number of digits)
+#hex.decode("abc")
+
+---
+// Error: 13-19 failed to decode hex (invalid digits `+f`)
+#hex.decode("ab+f")
+
+---
+// Test percent-encoding.
+#test(percent-encode("a-z_0.9~"), "a-z_0.9~")
+#test(percent-encode("typst & friends"), "typst%20%26%20friends")
+#test(percent-encode("ä/?"), "%C3%A4%2F%3F")
+#test(percent-decode("typst%20%26%20friends"), "typst & friends")
+#test(percent-decode("%c3%a4+b"), "ä+b")
+#test(percent-decode(percent-encode("Ünïcödé = 🦏")), "Ünïcödé = 🦏")
+
+---
+// Error: 17-25 failed to decode percent-encoding (invalid escape sequence)
+#percent-decode("100%!!")
+
+---
+// Error: 17-22 failed to decode percent-encoding (invalid UTF-8)
+#percent-decode("%FF")
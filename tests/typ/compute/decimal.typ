@@ -0,0 +1,71 @@
+// Test decimals.
+// Ref: false
+
+---
+// Test decimal constructor.
+#test(decimal("1.50"), decimal("1.5"))
+#test(decimal("-2"), -decimal(2))
+#test(decimal("\u{2212}2"), decimal(-2))
+#test(decimal(0.1), decimal("0.1"))
+#test(decimal(3), 3)
+#test(type(decimal(1)), decimal)
+#test(repr(decimal("1.50")), "decimal(\"1.5\")")
+#test(str(decimal("-2.50")), "\u{2212}2.5")
+#test(float(decimal("0.25")), 0.25)
+
+---
+// Test decimal arithmetic.
+#test(decimal("0.1") + decimal("0.2"), decimal("0.3"))
+#test(decimal("19.99") * 3, decimal("59.97"))
+#test(decimal("10") / 4, decimal("2.5"))
+#test(1 - decimal("0.01"), decimal("0.99"))
+#test((decimal(1) / 3).display(digits: 4), "0.3333")
+#test(decimal("-1.5") < 1, true)
+#test(decimal("2.05") > decimal("2.005"), true)
+#test(calc.max(decimal("1.1"), decimal("1.01")), decimal("1.1"))
+
+---
+// Test products of values with many decimal places.
+#let third = decimal(1) / 3
+#let two-thirds = decimal(2) / 3
+#test(third * two-thirds, decimal("0.2222222222222222222222222222"))
+#test(third * third, decimal("0.1111111111111111111111111111"))
+#test(-third * two-thirds, decimal("-0.2222222222222222222222222222"))
+#test(
+  decimal("123456789.0123456789012345678") * two-thirds,
+  decimal("82304526.0082304526008230452041152263"),
+)
+
+---
+// Test decimal display.
+#test(decimal("1234567.891").display(separator: ",", digits: 2), "1,234,567.89")
+#test(decimal("2.345").display(digits: 2), "2.35")
+#test(decimal("-0.004").display(digits: 2), "0.00")
+#test(decimal("12").display(digits: 2), "12.00")
+#test(decimal("-1234").display(separator: " "), "\u{2212}1 234")
+#test(
+  decimal("1234.5").display(separator: ".", decimal-mark: ",", digits: 2),
+  "1.234,50",
+)
+#test(decimal("1234.5").display(lang: "de", digits: 2), "1.234,50")
+#test(decimal("1234.5").display(lang: "de", separator: ""), "1234,5")
+
+---
+// Error: 10-17 invalid decimal: 1.2.3
+#decimal("1.2.3")
+
+---
+// Error: 10-18 cannot convert NaN to a decimal
+#decimal(calc.nan)
+
+---
+// Error: 3-23 cannot add decimal and float
+#(decimal("0.1") + 0.2)
+
+---
+// Error: 3-17 cannot divide by zero
+#(decimal(1) / 0)
+
+---
+// Error: 3-74 value is too large
+#((decimal("-170141183460469231731687303715884105727") - 1) / decimal(-1))
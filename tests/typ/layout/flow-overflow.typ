@@ -0,0 +1,18 @@
+// Test warnings for content that overflows the page.
+// Ref: false
+
+---
+#set page(width: 100pt, height: 100pt)
+// Warning: 2-35 content overflows the page horizontally by 20pt
+#block(width: 100pt, height: 10pt)
+
+---
+#set page(width: 100pt, height: 100pt)
+// Warning: 2-40 content overflows the page vertically by 20pt
+#block(breakable: false, height: 100pt)
+
+---
+// Content that fits or is in a container doesn't warn.
+#set page(width: 100pt, height: 100pt)
+#block(width: 100%, height: 80pt)
+#box(width: 60pt, block(width: 100pt))
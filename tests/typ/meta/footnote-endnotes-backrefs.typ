@@ -0,0 +1,12 @@
+// Test the back-references of endnotes.
+
+---
+// Each entry links back to its marker and to the markers referencing it.
+#set page(height: 120pt)
+#set footnote(endnote: true)
+
+A claim.#footnote[A source.] <fn>
+Another claim.#footnote[Another source.]
+The first again.#footnote(<fn>)
+
+#footnote.endnotes()
@@ -0,0 +1,40 @@
+// Test endnotes.
+// Ref: false
+
+---
+// Each listing collects the endnotes since the previous one. Regular
+// footnotes stay at the bottom of the page.
+#set footnote(endnote: true)
+#let seen(key) = it => {
+  state(key, ()).update(notes => notes + (it.note.body.text,))
+  it
+}
+
+= Introduction
+Some claim.#footnote[First]
+Another one.#footnote[Second]
+
+#[
+  #show footnote.entry: seen("intro")
+  #footnote.endnotes()
+]
+
+= Conclusion
+Mixed #footnote(endnote: false)[Bottom]
+and collected.#footnote[Last]
+
+#[
+  #show footnote.entry: seen("conclusion")
+  #footnote.endnotes()
+]
+
+#locate(loc => {
+  test(state("intro").final(loc), ("First", "Second"))
+  test(state("conclusion").final(loc), ("Last",))
+  test(query(footnote, loc).map(note => note.endnote), (true, true, false, true))
+})
+
+---
+// Test that an empty listing is fine.
+#show footnote.entry: it => panic("unexpected entry")
+#footnote.endnotes()
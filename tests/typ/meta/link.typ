@@ -74,4 +74,6 @@ Text <hey>
 Text <hey>
 Text <hey>
 // Error: 2-20 label `<hey>` occurs multiple times in the document
+// Related: -5:1--5:5 label is attached here
+// Related: -4:1--4:5 label is attached here
 #link(<hey>)[Nope.]
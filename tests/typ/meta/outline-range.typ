@@ -0,0 +1,71 @@
+// Test page ranges in the outline.
+// Ref: false
+
+---
+#set page(height: 200pt)
+#let expected = (
+  Beginnings: [2] + [–] + [3],
+  Middle: [4] + [–] + [5],
+  Details: [4] + [–] + [5],
+  Endings: [6],
+)
+#show outline.entry: it => {
+  test(it.page, expected.at(it.element.body.text))
+  it
+}
+
+#outline(page-range: true)
+#pagebreak()
+
+= Beginnings
+#pagebreak()
+More beginnings.
+#pagebreak()
+
+= Middle
+== Details
+#pagebreak()
+More details.
+#pagebreak()
+
+= Endings
+
+---
+// The end of a range is shown with the numbering of its own page.
+#set page(height: 200pt, numbering: "i")
+#let expected = (
+  Preface: [ii] + [–] + [1],
+  Main: [2],
+)
+#show outline.entry: it => {
+  test(it.page, expected.at(it.element.body.text))
+  it
+}
+
+#outline(page-range: true)
+#pagebreak()
+
+= Preface
+#set page(numbering: "1")
+#counter(page).update(1)
+More preface.
+#pagebreak()
+
+= Main
+
+---
+// Arabic page numbers are grouped like numbers in the text language.
+#set page(height: 100pt)
+#set text(lang: "de")
+#show outline.entry: it => {
+  test(it.page, [1.233] + [–] + [1.234])
+  it
+}
+
+#outline(page-range: true)
+#counter(page).update(1232)
+#pagebreak()
+
+= Anhang
+#pagebreak()
+Mehr.
@@ -18,6 +18,8 @@ As seen in @intro, we proceed.
 = Second <foo>
 
 // Error: 1-5 label `<foo>` occurs multiple times in the document
+// Related: -6:1--6:8 label is attached here
+// Related: -5:1--5:9 label is attached here
 @foo
 
 ---
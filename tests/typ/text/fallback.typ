@@ -17,4 +17,6 @@ Aب😀🏞سمB
 01️⃣2
 
 // Tofus are rendered with the first font.
+// Warning: 1-6 no font contains the characters `ዲ`, `ሞ`
+// Hint: 1-6 tried font families: Linux Libertine and fallback fonts
 A🐈ዲሞB
@@ -33,6 +33,8 @@ Emoji: 🐪, 🌋, 🏞
 // Disable font fallback beyond the user-specified list.
 // Without disabling, New Computer Modern Math would come to the rescue.
 #set text(font: ("PT Sans", "Twitter Color Emoji"), fallback: false)
+// Warning: 4-7 no font contains the characters `𝛼`, `𝛽`
+// Hint: 4-7 tried font families: PT Sans, Twitter Color Emoji
 2π = 𝛼 + 𝛽. ✅
 
 ---
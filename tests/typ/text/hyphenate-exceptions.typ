@@ -0,0 +1,26 @@
+// Test custom hyphenation exceptions.
+
+---
+// Exceptions replace the hyphenation points of their words, regardless of
+// case. Words without hyphens in the list are never hyphenated.
+#set page(width: 30pt)
+#set text(hyphenate: true, hyphenation: ("data-base", "FORTRAN", "é-té"))
+database
+
+Database
+
+Fortran
+
+été
+
+---
+// Without exceptions, the same words break at their regular points.
+#set page(width: 30pt)
+#set text(hyphenate: true)
+database
+
+Fortran
+
+---
+// Error: 24-25 expected array, found integer
+#set text(hyphenation: 1)
@@ -0,0 +1,18 @@
+// Test keeping content on one line.
+
+---
+// Lines don't break within the content, not even at hyphenation points.
+// Instead, the whole content moves to the next line and overflows it.
+#set page(width: 80pt)
+#set text(hyphenate: true)
+#let caption = [Figure 3 and its extraordinarily long caption]
+#caption
+
+#nobreak(caption)
+
+See #nobreak[Figure 3] here.
+
+---
+// Explicit line breaks are still honored.
+#set page(width: 80pt)
+#nobreak[A \ B]
@@ -0,0 +1,41 @@
+// Test word-level highlighting in diffs.
+// Ref: false
+
+---
+#let marked(line) = {
+  let children = line.body.fields().at("children", default: ())
+  children.filter(c => c.func() == highlight)
+}
+
+#show raw.line: it => {
+  let expected = if it.number in (2, 3) { 1 } else { 0 }
+  test(marked(it).len(), expected)
+  it
+}
+
+#set raw(word-diff: true)
+```diff
+ let x = 1;
+-let y = x + 2;
++let y = x * 2;
+ let z = y;
+-print(x)
+```
+
+---
+// Lines without anything in common are not highlighted.
+#let marked(line) = {
+  let children = line.body.fields().at("children", default: ())
+  children.filter(c => c.func() == highlight)
+}
+
+#show raw.line: it => {
+  test(marked(it), ())
+  it
+}
+
+#set raw(word-diff: true)
+```diff
+-alpha
++beta
+```
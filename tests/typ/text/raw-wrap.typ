@@ -0,0 +1,60 @@
+// Test the wrapping modes of raw blocks.
+
+---
+// Soft wrapping marks each broken line with a continuation marker.
+#set page(width: 120pt)
+```rust
+let path = some-long-name/with-many-parts/and-more;
+let x = 1;
+```
+
+---
+// Clipping keeps lines intact and cuts them off at the block's edge.
+#set page(width: 120pt)
+#set raw(wrap: "clip")
+```rust
+let path = some-long-name/with-many-parts/and-more;
+let x = 1;
+```
+
+---
+// Shrinking scales the text down until the longest line fits.
+#set page(width: 120pt)
+#set raw(wrap: "shrink")
+```rust
+let path = some-long-name/with-many-parts/and-more;
+let x = 1;
+```
+
+---
+// Blocks that fit are not scaled up.
+#set page(width: 120pt)
+#set raw(wrap: "shrink")
+```rust
+let x = 1;
+```
+
+---
+// Inline raw text isn't marked or shrunk, but kept intact.
+#set page(width: 120pt)
+Wrapped `let path = some-long-name/with-many-parts` text.
+
+#set raw(wrap: "shrink")
+Kept `let x = some/path` intact.
+
+---
+// The text of the lines is kept as it is.
+#show raw.line: it => {
+  test(it.text, "let x = 1")
+  test(it.body.text, "let x = 1")
+  it
+}
+
+#set raw(wrap: "clip")
+```
+let x = 1
+```
+
+---
+// Error: 16-21 expected "soft", "clip", or "shrink", found boolean
+#set raw(wrap: false)
@@ -0,0 +1,7 @@
+// Test that the EXIF orientation of photos is respected.
+
+---
+// The orientation tag of the second photo turns it by a quarter.
+#set page(height: auto)
+#image("/files/tiger.jpg", width: 40%)
+#image("/files/tiger-rotated.jpg", width: 40%)
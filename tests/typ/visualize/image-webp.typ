@@ -0,0 +1,9 @@
+// Test WebP images.
+
+---
+// A 1x1 lossless WebP image keeps its aspect ratio, whether the format is
+// detected or given explicitly.
+#let data = base64.decode("UklGRhoAAABXRUJQVlA4TA0AAAAvAAAAEAcQERGIiP4HAA==")
+#set page(width: auto, height: auto)
+#box(stroke: 0.5pt, image.decode(data, width: 10pt))
+#box(stroke: 0.5pt, image.decode(data, format: "webp", height: 5pt))
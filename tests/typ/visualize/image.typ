@@ -80,3 +80,20 @@ A #box(image("/files/tiger.jpg", height: 1cm, width: 80%)) B
 ---
 // Error: 2-83 failed to decode image (Format error decoding Png: Invalid PNG signature.)
 #image.decode(read("/files/tiger.jpg", encoding: none), format: "png", width: 80%)
+
+---
+// Test that cropping keeps the size of the remaining part.
+#set page(height: auto)
+#let cropped(crop) = box(stroke: 0.5pt, image("/files/tiger.jpg", width: 40pt, crop: crop))
+#cropped(0%)
+#cropped((x: 25%))
+#cropped((top: 50%))
+#cropped((left: 20%, bottom: 60%))
+
+---
+// Error: 2-52 cropping must leave part of the image visible
+#image("/files/tiger.jpg", crop: (x: 50%, top: 5%))
+
+---
+// Error: 2-46 cropping must be between 0% and 100% on each side
+#image("/files/tiger.jpg", crop: (top: -10%))
@@ -54,6 +54,7 @@
 // Test that square doesn't overflow due to its aspect ratio.
 #set page(width: 40pt, height: 25pt, margin: 5pt)
 #square(width: 100%)
+// Warning: 2-34 content overflows the page vertically by 15pt
 #square(width: 100%)[Hello there]
 
 ---
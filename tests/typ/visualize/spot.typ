@@ -0,0 +1,21 @@
+// Test spot colors.
+// Ref: false
+
+---
+#let brand = spot("PANTONE 300 C", cmyk(100%, 0%, 0%, 0%))
+#test(type(brand), spot)
+#test(brand.name(), "PANTONE 300 C")
+#test(brand.fallback(), cmyk(100%, 0%, 0%, 0%))
+#test(repr(brand), "spot(\"PANTONE 300 C\", cmyk(100%, 0%, 0%, 0%))")
+#test(brand, spot("PANTONE 300 C", cmyk(100%, 0%, 0%, 0%)))
+
+---
+// Test spot colors as fills and strokes.
+#let brand = spot("Brand Blue", rgb("#0044aa"))
+#rect(fill: brand, stroke: (paint: brand, thickness: 2pt))
+#text(fill: brand)[Brand]
+#line(length: 100%, stroke: (paint: brand))
+
+---
+// Error: 6-15 spot color name must not be empty
+#spot("", red)